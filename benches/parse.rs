@@ -0,0 +1,65 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lenient_bool::{LenientBool, LenientBoolParser};
+
+/// A mix of values like a typical CSV column: mostly valid, in assorted cases, with some
+/// invalid and long values.
+const INPUTS: &[&str] = &[
+    "true",
+    "FALSE",
+    "Yes",
+    "no",
+    "Y",
+    "n",
+    "1",
+    "0",
+    "t",
+    "F",
+    "maybe",
+    "",
+    "yesterday",
+    "this is not a boolean value",
+];
+
+fn from_str(c: &mut Criterion) {
+    c.bench_function("from_str mixed", |b| {
+        b.iter(|| {
+            for s in INPUTS {
+                let _ = black_box(black_box(s).parse::<LenientBool>());
+            }
+        })
+    });
+
+    let mut group = c.benchmark_group("from_str");
+    for s in ["true", "FALSE", "n", "maybe", "this is not a boolean value"] {
+        group.bench_function(s, |b| b.iter(|| black_box(s).parse::<LenientBool>()));
+    }
+    group.finish();
+}
+
+fn from_bytes(c: &mut Criterion) {
+    c.bench_function("from_bytes mixed", |b| {
+        b.iter(|| {
+            for s in INPUTS {
+                let _ = black_box(LenientBool::from_bytes(black_box(s.as_bytes())));
+            }
+        })
+    });
+}
+
+fn custom_tokens(c: &mut Criterion) {
+    let synonyms = (0..500).map(|i| format!("synonym-{}", i));
+    let parser = LenientBoolParser::new().true_tokens(synonyms);
+    let compiled = parser.clone().compile();
+
+    let mut group = c.benchmark_group("500 tokens");
+    group.bench_function("linear", |b| {
+        b.iter(|| parser.parse(black_box("SYNONYM-499")))
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| compiled.parse(black_box("SYNONYM-499")))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, from_str, from_bytes, custom_tokens);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+//! The `LenientFromStr` derive macro. It is re-exported by `lenient_bool` with the `derive`
+//! feature, which is where it is documented.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, parse_macro_input, Data, DeriveInput, Error, Fields, LitStr, Token};
+
+#[proc_macro_derive(LenientFromStr, attributes(lenient))]
+pub fn derive_lenient_from_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The spellings given in a variant's `#[lenient(tokens(...))]` attributes.
+fn tokens(variant: &syn::Variant) -> syn::Result<Vec<LitStr>> {
+    let mut tokens = Vec::new();
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("lenient"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("tokens") {
+                return Err(meta.error("expected `tokens(...)`"));
+            }
+            let content;
+            parenthesized!(content in meta.input);
+            tokens.extend(Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?);
+            Ok(())
+        })?;
+    }
+    Ok(tokens)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "LenientFromStr can only be derived for enums",
+        ));
+    };
+
+    let mut table = Vec::new();
+    let mut arms = Vec::new();
+    for (index, variant) in data.variants.iter().enumerate() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "LenientFromStr only supports variants without fields",
+            ));
+        }
+        let mut tokens = tokens(variant)?;
+        if tokens.is_empty() {
+            tokens.push(LitStr::new(
+                &variant.ident.to_string(),
+                variant.ident.span(),
+            ));
+        }
+        table.extend(tokens.iter().map(|token| quote!((#token, #index))));
+        let ident = &variant.ident;
+        arms.push(quote!(::core::option::Option::Some(#index) => ::core::result::Result::Ok(Self::#ident)));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::lenient_bool::ParseEnumError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, ::lenient_bool::ParseEnumError> {
+                const TOKENS: &[(&str, usize)] = &[#(#table),*];
+                match ::lenient_bool::match_token(s, TOKENS) {
+                    #(#arms,)*
+                    _ => ::core::result::Result::Err(
+                        ::lenient_bool::ParseEnumError::new(::core::stringify!(#name), s),
+                    ),
+                }
+            }
+        }
+    })
+}
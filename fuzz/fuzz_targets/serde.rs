@@ -0,0 +1,17 @@
+#![no_main]
+
+use lenient_bool::LenientBool;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|json: &str| {
+    // The serde visitor accepts exactly the JSON strings that `FromStr` accepts.
+    let parsed = serde_json::from_str::<LenientBool>(json);
+    let expected = serde_json::from_str::<String>(json)
+        .ok()
+        .and_then(|s| s.parse::<LenientBool>().ok());
+    assert_eq!(parsed.ok(), expected, "{:?}", json);
+
+    if let Ok(s) = serde_json::from_str::<String>(json) {
+        lenient_bool::invariants::check_str(&s);
+    }
+});
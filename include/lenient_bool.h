@@ -0,0 +1,52 @@
+/*
+ * C interface to the lenient_bool crate, enabled with the `ffi` feature.
+ *
+ * Build the library with
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ * or `--crate-type staticlib` for static linking.
+ */
+
+#ifndef LENIENT_BOOL_H
+#define LENIENT_BOOL_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Return codes. These values are stable. */
+#define LENIENT_BOOL_OK 0
+#define LENIENT_BOOL_INVALID 1
+#define LENIENT_BOOL_NULL_POINTER 2
+
+/*
+ * Parses the NUL-terminated string `s`, storing the result in `out` on success.
+ *
+ * Returns LENIENT_BOOL_OK on success, LENIENT_BOOL_INVALID if the string isn't recognized,
+ * or LENIENT_BOOL_NULL_POINTER if either pointer is null. `out` is only written on success.
+ */
+int lenient_bool_parse(const char *s, bool *out);
+
+/*
+ * Parses the `len` bytes at `s`, which don't need to be NUL-terminated, storing the result
+ * in `out` on success. Returns the same codes as lenient_bool_parse.
+ */
+int lenient_bool_parse_len(const char *s, size_t len, bool *out);
+
+/* Returns the number of accepted tokens. */
+size_t lenient_bool_token_count(void);
+
+/*
+ * Returns the accepted token at `index` as a static NUL-terminated string, or NULL if
+ * `index` is out of range. If `value` is not NULL, the boolean that the token parses to is
+ * stored there. Tokens are matched case-insensitively, and are returned in lowercase.
+ */
+const char *lenient_bool_token(size_t index, bool *value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LENIENT_BOOL_H */
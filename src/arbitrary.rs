@@ -0,0 +1,116 @@
+//! Integration with `arbitrary`, for structure-aware fuzzing.
+//!
+//! `LenientBool` implements `Arbitrary`, so it can be embedded in fuzzed types that derive
+//! `Arbitrary`. `ValidToken` and `InvalidToken` generate strings that do and don't parse,
+//! for exercising code that parses lenient booleans itself.
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use lenient_bool::arbitrary::ValidToken;
+//! use lenient_bool::LenientBool;
+//!
+//! let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+//! let token = ValidToken::arbitrary(&mut u).unwrap();
+//! assert_eq!(token.text.parse(), Ok(LenientBool(token.value)));
+//! ```
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+impl<'a> Arbitrary<'a> for LenientBool {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<LenientBool> {
+        bool::arbitrary(u).map(LenientBool)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        bool::size_hint(depth)
+    }
+}
+
+/// A string that parses as a `LenientBool`: one of the default tokens, with the case of each
+/// character chosen at random.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidToken {
+    pub text: String,
+    /// The value that `text` parses to.
+    pub value: bool,
+}
+
+impl<'a> Arbitrary<'a> for ValidToken {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<ValidToken> {
+        let value = bool::arbitrary(u)?;
+        let token = u.choose(if value { TRUE_TOKENS } else { FALSE_TOKENS })?;
+        let mut text = String::with_capacity(token.len());
+        for c in token.chars() {
+            if bool::arbitrary(u)? {
+                text.extend(c.to_uppercase());
+            } else {
+                text.push(c);
+            }
+        }
+        Ok(ValidToken { text, value })
+    }
+}
+
+/// A string that doesn't parse as a `LenientBool`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidToken(pub String);
+
+impl<'a> Arbitrary<'a> for InvalidToken {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<InvalidToken> {
+        let mut text = String::arbitrary(u)?;
+        // No token contains a `?`, so adding one to a string that happens to parse makes it
+        // invalid.
+        if text.parse::<LenientBool>().is_ok() {
+            text.push('?');
+        }
+        Ok(InvalidToken(text))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn inputs() -> impl Iterator<Item = Vec<u8>> {
+        (0u8..=255).map(|seed| {
+            (0..16)
+                .map(|i| seed.wrapping_mul(31).wrapping_add(i * 7))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn lenient_bool() {
+        let mut u = Unstructured::new(&[1]);
+        assert_eq!(LenientBool::arbitrary(&mut u), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn valid_tokens_parse() {
+        for data in inputs() {
+            let token = ValidToken::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert_eq!(
+                token.text.parse(),
+                Ok(LenientBool(token.value)),
+                "{:?}",
+                token
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_tokens_fail() {
+        for data in inputs() {
+            let token = InvalidToken::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert!(token.0.parse::<LenientBool>().is_err(), "{:?}", token);
+        }
+        for data in [&b"y"[..], b"1", b"no"] {
+            // The length of the string comes from the last byte.
+            let bytes = [data, &[data.len() as u8]].concat();
+            let token = InvalidToken::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            assert_eq!(token.0.as_bytes(), [data, b"?"].concat(), "{:?}", token);
+        }
+    }
+}
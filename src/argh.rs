@@ -0,0 +1,46 @@
+//! Using `LenientBool` with `argh` argument parsing.
+//!
+//! `LenientBool` can be used as the type of an `argh` option through argh's implementation
+//! of `FromArgValue` for `FromStr` types, and invalid values are reported with the
+//! `LenientBoolError` message.
+//!
+//! ```
+//! use argh::FromArgs;
+//! use lenient_bool::LenientBool;
+//!
+//! #[derive(FromArgs)]
+//! /// An example command.
+//! struct Args {
+//!     /// whether to use color
+//!     #[argh(option)]
+//!     color: LenientBool,
+//! }
+//!
+//! let args = Args::from_args(&["app"], &["--color", "yes"]).unwrap();
+//! assert!(*args.color);
+//! ```
+
+#[cfg(test)]
+mod test {
+    use ::argh::FromArgValue;
+
+    use crate::LenientBool;
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(LenientBool::from_arg_value("yes"), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn parse_n() {
+        assert_eq!(LenientBool::from_arg_value("n"), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        assert_eq!(
+            LenientBool::from_arg_value("abc"),
+            Err("invalid boolean value \"abc\"".to_owned())
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Converting Arrow string arrays to boolean arrays.
+//!
+//! `cast_to_boolean` parses every value of a `StringArray` or `LargeStringArray` with the
+//! `LenientBool` rules. Null inputs stay null, and `OnInvalid` decides whether unrecognized
+//! values become nulls or fail the whole conversion. `cast_to_boolean_collect` turns them
+//! into nulls and also reports every one of them.
+//!
+//! ```
+//! use arrow_array::{BooleanArray, StringArray};
+//! use lenient_bool::arrow::{cast_to_boolean, OnInvalid};
+//!
+//! let input = StringArray::from(vec![Some("Y"), None, Some("maybe"), Some("0")]);
+//!
+//! let output = cast_to_boolean(&input, OnInvalid::Null).unwrap();
+//! assert_eq!(output, BooleanArray::from(vec![Some(true), None, None, Some(false)]));
+//!
+//! let err = cast_to_boolean(&input, OnInvalid::Error).unwrap_err();
+//! assert_eq!(err.to_string(), "invalid boolean value \"maybe\" at index 2");
+//! ```
+
+use std::error;
+use std::fmt;
+
+use ::arrow_array::{BooleanArray, GenericStringArray, OffsetSizeTrait};
+
+use super::LenientBool;
+
+/// What to do with a value that isn't recognized as a boolean.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum OnInvalid {
+    /// Store a null in its place.
+    #[default]
+    Null,
+    /// Stop and return an error.
+    Error,
+}
+
+/// A value that could not be parsed as a boolean.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidValue {
+    /// The index of the value in the input array.
+    pub index: usize,
+    pub value: String,
+}
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid boolean value {:?} at index {}",
+            self.value, self.index
+        )
+    }
+}
+
+impl error::Error for InvalidValue {}
+
+/// Parses each value of `array` as a boolean.
+///
+/// The output has the same length as the input. Its validity mask is the input's, with
+/// unrecognized values also marked null when `on_invalid` is `OnInvalid::Null`.
+pub fn cast_to_boolean<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    on_invalid: OnInvalid,
+) -> Result<BooleanArray, InvalidValue> {
+    array
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let Some(value) = value else {
+                return Ok(None);
+            };
+            match (value.parse::<LenientBool>(), on_invalid) {
+                (Ok(b), _) => Ok(Some(b.0)),
+                (Err(_), OnInvalid::Null) => Ok(None),
+                (Err(_), OnInvalid::Error) => Err(InvalidValue {
+                    index,
+                    value: value.to_owned(),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Parses each value of `array` as a boolean, turning unrecognized values into nulls and
+/// returning them alongside the output, for reporting every bad value in a column at once.
+///
+/// ```
+/// use arrow_array::{BooleanArray, StringArray};
+/// use lenient_bool::arrow::cast_to_boolean_collect;
+///
+/// let input = StringArray::from(vec![Some("maybe"), Some("t"), None, Some("?")]);
+/// let (output, invalid) = cast_to_boolean_collect(&input);
+/// assert_eq!(output, BooleanArray::from(vec![None, Some(true), None, None]));
+/// assert_eq!(invalid.iter().map(|v| v.index).collect::<Vec<_>>(), [0, 3]);
+/// ```
+pub fn cast_to_boolean_collect<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+) -> (BooleanArray, Vec<InvalidValue>) {
+    let mut invalid = Vec::new();
+    let output = array
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let value = value?;
+            let parsed = value.parse::<LenientBool>().ok().map(bool::from);
+            if parsed.is_none() {
+                invalid.push(InvalidValue {
+                    index,
+                    value: value.to_owned(),
+                });
+            }
+            parsed
+        })
+        .collect();
+    (output, invalid)
+}
+
+#[cfg(test)]
+mod test {
+    use ::arrow_array::{Array, LargeStringArray, StringArray};
+
+    use super::*;
+
+    #[test]
+    fn cast_values() {
+        let input = StringArray::from(vec!["yes", "F", "1"]);
+        let output = cast_to_boolean(&input, OnInvalid::Error).unwrap();
+        assert_eq!(output, BooleanArray::from(vec![true, false, true]));
+        assert_eq!(output.null_count(), 0);
+    }
+
+    #[test]
+    fn cast_keeps_nulls() {
+        let input = LargeStringArray::from(vec![None, Some("no")]);
+        let output = cast_to_boolean(&input, OnInvalid::Error).unwrap();
+        assert_eq!(output, BooleanArray::from(vec![None, Some(false)]));
+    }
+
+    #[test]
+    fn cast_invalid_as_null() {
+        let input = StringArray::from(vec!["abc", "t"]);
+        let output = cast_to_boolean(&input, OnInvalid::Null).unwrap();
+        assert_eq!(output, BooleanArray::from(vec![None, Some(true)]));
+    }
+
+    #[test]
+    fn cast_collect() {
+        let input = LargeStringArray::from(vec![Some("abc"), None, Some("N"), Some("")]);
+        let (output, invalid) = cast_to_boolean_collect(&input);
+        assert_eq!(
+            output,
+            BooleanArray::from(vec![None, None, Some(false), None])
+        );
+        assert_eq!(
+            invalid,
+            [
+                InvalidValue {
+                    index: 0,
+                    value: "abc".to_owned()
+                },
+                InvalidValue {
+                    index: 3,
+                    value: "".to_owned()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn cast_collect_valid() {
+        let input = StringArray::from(vec!["yes", "0"]);
+        let (output, invalid) = cast_to_boolean_collect(&input);
+        assert_eq!(output, BooleanArray::from(vec![true, false]));
+        assert!(invalid.is_empty());
+    }
+
+    #[test]
+    fn cast_invalid_err() {
+        let input = StringArray::from(vec!["t", "abc"]);
+        assert_eq!(
+            cast_to_boolean(&input, OnInvalid::Error),
+            Err(InvalidValue {
+                index: 1,
+                value: "abc".to_owned()
+            })
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Integration with `async-graphql`.
+
+use ::async_graphql::{InputValueError, InputValueResult, Number, Scalar, ScalarType, Value};
+
+use super::LenientBool;
+
+/// A `LenientBool` scalar, for clients that send flags as strings.
+///
+/// Inputs may be a native boolean, one of the strings that `LenientBool` parses, such as
+/// `"yes"` or `"1"`, or the integers 0 and 1. Anything else is an input error that names the
+/// value and lists the accepted spellings. Outputs are always native booleans.
+#[Scalar(name = "LenientBool")]
+impl ScalarType for LenientBool {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::Boolean(b) => Ok(LenientBool(*b)),
+            Value::String(s) => s
+                .parse()
+                .map_err(|err| InputValueError::custom(format!("{:#}", err))),
+            Value::Number(n) if *n == Number::from(0) => Ok(LenientBool(false)),
+            Value::Number(n) if *n == Number::from(1) => Ok(LenientBool(true)),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    // Query validation only reports that a literal has the wrong type, so strings and numbers
+    // are let through to `parse`, which says what was wrong with them.
+    fn is_valid(value: &Value) -> bool {
+        matches!(
+            value,
+            Value::Boolean(_) | Value::String(_) | Value::Number(_)
+        )
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Boolean(self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+    use super::*;
+
+    fn parse(value: Value) -> Option<LenientBool> {
+        <LenientBool as ScalarType>::parse(value).ok()
+    }
+
+    #[test]
+    fn parse_values() {
+        assert_eq!(parse(Value::Boolean(true)), Some(LenientBool(true)));
+        assert_eq!(parse(Value::from("N")), Some(LenientBool(false)));
+        assert_eq!(parse(Value::from("1")), Some(LenientBool(true)));
+        assert_eq!(parse(Value::from(0)), Some(LenientBool(false)));
+        assert_eq!(parse(Value::from(2)), None);
+        assert_eq!(parse(Value::Null), None);
+        assert!(LenientBool::is_valid(&Value::from("maybe")));
+        assert!(!LenientBool::is_valid(&Value::Null));
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn echo(&self, value: LenientBool) -> LenientBool {
+            value
+        }
+    }
+
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+    }
+
+    #[tokio::test]
+    async fn query_argument() {
+        let response = schema().execute(r#"{ echo(value: "yes") }"#).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(response.data.to_string(), "{echo: true}");
+
+        let response = schema().execute("{ echo(value: false) }").await;
+        assert_eq!(response.data.to_string(), "{echo: false}");
+    }
+
+    #[tokio::test]
+    async fn query_invalid_argument() {
+        let response = schema().execute(r#"{ echo(value: "maybe") }"#).await;
+        let message = &response.errors[0].message;
+        assert!(message.contains(r#""maybe""#), "{}", message);
+        assert!(message.contains("Expected one of: true"), "{}", message);
+    }
+}
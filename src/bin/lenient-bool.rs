@@ -0,0 +1,190 @@
+//! Parses a value with the `LenientBool` rules, for shell scripts.
+//!
+//! ```text
+//! lenient-bool [--print] [--strictness strict|standard|extended] [VALUE]
+//! ```
+//!
+//! The value is read from standard input when it isn't given as an argument, with one
+//! trailing line ending removed. The exit status is 0 for `true`, 1 for `false`, 2 for a
+//! value that isn't recognized, and 64 for a usage error. `--print` also writes the
+//! canonical form, `true` or `false`, to standard output.
+//!
+//! ```sh
+//! if lenient-bool "$ENABLE_FOO"; then
+//!     echo "foo is on"
+//! fi
+//! ```
+
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use lenient_bool::{LenientBool, Strictness};
+
+const USAGE: &str = "usage: lenient-bool [--print] [--strictness strict|standard|extended] [VALUE]";
+
+/// The exit status for a value that isn't recognized.
+const INVALID: u8 = 2;
+/// The exit status for bad arguments, as in BSD's `sysexits.h`.
+const USAGE_ERROR: u8 = 64;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    print: bool,
+    strictness: Strictness,
+    value: Option<String>,
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Options, String> {
+    let mut options = Options {
+        print: false,
+        strictness: Strictness::Standard,
+        value: None,
+    };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--print" => options.print = true,
+            "--strictness" => {
+                options.strictness = match args.next().as_deref() {
+                    Some("strict") => Strictness::Strict,
+                    Some("standard") => Strictness::Standard,
+                    Some("extended") => Strictness::Extended,
+                    Some(other) => return Err(format!("unknown strictness {:?}", other)),
+                    None => return Err("--strictness needs a value".to_owned()),
+                }
+            }
+            // Everything after `--` is the value, so that values starting with `-` can be
+            // passed.
+            "--" => {
+                options.value = args.next();
+                break;
+            }
+            s if s.starts_with('-') && s.len() > 1 => {
+                return Err(format!("unknown option {:?}", s));
+            }
+            _ if options.value.is_some() => return Err("only one value can be given".to_owned()),
+            _ => options.value = Some(arg),
+        }
+    }
+    if args.next().is_some() {
+        return Err("only one value can be given".to_owned());
+    }
+    Ok(options)
+}
+
+/// Parses `value`, writing the canonical form to `out` if asked to, and returns the exit
+/// status.
+fn run(options: &Options, value: &str, out: &mut impl Write) -> io::Result<u8> {
+    match LenientBool::from_str_with(value, options.strictness) {
+        Ok(b) => {
+            if options.print {
+                writeln!(out, "{}", b)?;
+            }
+            Ok(if b.0 { 0 } else { 1 })
+        }
+        // The alternate form lists the standard spellings, which would be misleading for
+        // the other strictness levels.
+        Err(err) if options.strictness == Strictness::Standard => {
+            eprintln!("lenient-bool: {:#}", err);
+            Ok(INVALID)
+        }
+        Err(err) => {
+            eprintln!("lenient-bool: {}", err);
+            Ok(INVALID)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("lenient-bool: {}\n{}", message, USAGE);
+            return ExitCode::from(USAGE_ERROR);
+        }
+    };
+
+    let value = match &options.value {
+        Some(value) => value.clone(),
+        None => {
+            let mut input = String::new();
+            if let Err(err) = io::stdin().read_to_string(&mut input) {
+                eprintln!("lenient-bool: reading standard input: {}", err);
+                return ExitCode::from(INVALID);
+            }
+            let line = input.strip_suffix('\n').unwrap_or(&input);
+            line.strip_suffix('\r').unwrap_or(line).to_owned()
+        }
+    };
+
+    match run(&options, &value, &mut io::stdout().lock()) {
+        Ok(code) => ExitCode::from(code),
+        Err(err) => {
+            eprintln!("lenient-bool: {}", err);
+            ExitCode::from(INVALID)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    fn run_value(options: &Options, value: &str) -> (u8, String) {
+        let mut out = Vec::new();
+        let code = run(options, value, &mut out).unwrap();
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn parse_args_value() {
+        let options = args(&["-p", "Yes"]).unwrap();
+        assert!(options.print);
+        assert_eq!(options.value.as_deref(), Some("Yes"));
+        assert_eq!(args(&[]).unwrap().value, None);
+    }
+
+    #[test]
+    fn parse_args_strictness() {
+        let options = args(&["--strictness", "extended", "on"]).unwrap();
+        assert_eq!(options.strictness, Strictness::Extended);
+        assert!(args(&["--strictness", "loose"]).is_err());
+        assert!(args(&["--strictness"]).is_err());
+    }
+
+    #[test]
+    fn parse_args_errors() {
+        assert!(args(&["--verbose"]).is_err());
+        assert!(args(&["yes", "no"]).is_err());
+        assert_eq!(args(&["--", "-1"]).unwrap().value.as_deref(), Some("-1"));
+        assert_eq!(args(&["-"]).unwrap().value.as_deref(), Some("-"));
+    }
+
+    #[test]
+    fn exit_codes() {
+        let options = args(&[]).unwrap();
+        assert_eq!(run_value(&options, "Y"), (0, String::new()));
+        assert_eq!(run_value(&options, "0"), (1, String::new()));
+        assert_eq!(run_value(&options, "maybe"), (INVALID, String::new()));
+        assert_eq!(run_value(&options, ""), (INVALID, String::new()));
+    }
+
+    #[test]
+    fn print_canonical() {
+        let options = args(&["--print"]).unwrap();
+        assert_eq!(run_value(&options, "YES"), (0, "true\n".to_owned()));
+        assert_eq!(run_value(&options, "n"), (1, "false\n".to_owned()));
+    }
+
+    #[test]
+    fn strictness() {
+        let options = args(&["--strictness", "strict"]).unwrap();
+        assert_eq!(run_value(&options, "yes").0, INVALID);
+        let options = args(&["--strictness", "extended"]).unwrap();
+        assert_eq!(run_value(&options, "off").0, 1);
+    }
+}
@@ -0,0 +1,143 @@
+//! Parsing compact strings of bits, such as flag masks written as `1010 01`.
+
+use std::fmt;
+
+/// Which characters `parse_bitstring` accepts besides `0` and `1`.
+///
+/// By default, whitespace and `_` are allowed between bits and the letters `y` and `n` are
+/// not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitstringOptions {
+    separators: bool,
+    letters: bool,
+}
+
+impl Default for BitstringOptions {
+    fn default() -> BitstringOptions {
+        BitstringOptions {
+            separators: true,
+            letters: false,
+        }
+    }
+}
+
+impl BitstringOptions {
+    pub fn new() -> BitstringOptions {
+        BitstringOptions::default()
+    }
+
+    /// Whether whitespace and `_` may appear between bits. They are skipped.
+    pub fn separators(mut self, separators: bool) -> BitstringOptions {
+        self.separators = separators;
+        self
+    }
+
+    /// Whether `y` and `n`, in either case, are accepted as `true` and `false`.
+    pub fn letters(mut self, letters: bool) -> BitstringOptions {
+        self.letters = letters;
+        self
+    }
+}
+
+/// The error returned by `parse_bitstring` for a character that isn't a bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitstringError {
+    /// The byte offset of the character in the input.
+    pub offset: usize,
+    /// The character.
+    pub found: char,
+}
+
+impl fmt::Display for BitstringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid bit {:?} at offset {}", self.found, self.offset)
+    }
+}
+
+impl std::error::Error for BitstringError {}
+
+/// Parses a string of `0` and `1` characters into one `bool` per bit, in order.
+///
+/// ```
+/// use lenient_bool::{parse_bitstring, BitstringOptions};
+///
+/// let bits = parse_bitstring("1010 01", BitstringOptions::new()).unwrap();
+/// assert_eq!(bits, [true, false, true, false, false, true]);
+///
+/// let letters = BitstringOptions::new().letters(true);
+/// assert_eq!(parse_bitstring("YnY", letters), Ok(vec![true, false, true]));
+///
+/// let err = parse_bitstring("10x1", BitstringOptions::new()).unwrap_err();
+/// assert_eq!((err.offset, err.found), (2, 'x'));
+/// ```
+pub fn parse_bitstring(s: &str, options: BitstringOptions) -> Result<Vec<bool>, BitstringError> {
+    let mut bits = Vec::with_capacity(s.len());
+    for (offset, c) in s.char_indices() {
+        match c {
+            '0' => bits.push(false),
+            '1' => bits.push(true),
+            'y' | 'Y' if options.letters => bits.push(true),
+            'n' | 'N' if options.letters => bits.push(false),
+            '_' if options.separators => {}
+            c if options.separators && c.is_whitespace() => {}
+            found => return Err(BitstringError { offset, found }),
+        }
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bits() {
+        let options = BitstringOptions::new();
+        assert_eq!(
+            parse_bitstring("0110", options),
+            Ok(vec![false, true, true, false])
+        );
+        assert_eq!(parse_bitstring("", options), Ok(vec![]));
+    }
+
+    #[test]
+    fn separators() {
+        let options = BitstringOptions::new();
+        assert_eq!(
+            parse_bitstring(" 10_01\t1\n", options),
+            Ok(vec![true, false, false, true, true])
+        );
+
+        let err = parse_bitstring("10 01", options.separators(false)).unwrap_err();
+        assert_eq!(
+            err,
+            BitstringError {
+                offset: 2,
+                found: ' '
+            }
+        );
+    }
+
+    #[test]
+    fn letters() {
+        let options = BitstringOptions::new().letters(true);
+        assert_eq!(
+            parse_bitstring("yN 1n", options),
+            Ok(vec![true, false, true, false])
+        );
+        assert!(parse_bitstring("y", BitstringOptions::new()).is_err());
+    }
+
+    #[test]
+    fn error_offset_is_in_bytes() {
+        let err = parse_bitstring("1é0x", BitstringOptions::new()).unwrap_err();
+        assert_eq!(
+            err,
+            BitstringError {
+                offset: 1,
+                found: 'é'
+            }
+        );
+        assert_eq!(err.to_string(), "invalid bit 'é' at offset 1");
+    }
+}
@@ -0,0 +1,99 @@
+//! Parsing columns of values into a `BitVec`.
+//!
+//! `parse_bitvec` stores one bit per value instead of the byte that each `bool` of a
+//! `Vec<bool>` takes, which matters for columns with hundreds of millions of rows.
+//!
+//! ```
+//! use bitvec::prelude::*;
+//! use lenient_bool::bitvec::parse_bitvec;
+//!
+//! let bits = parse_bitvec(["Y", "n", "1", "false"]).unwrap();
+//! assert_eq!(bits, bits![1, 0, 1, 0]);
+//!
+//! let err = parse_bitvec(["yes", "maybe"]).unwrap_err();
+//! assert_eq!(err.to_string(), "invalid boolean value \"maybe\" at index 1");
+//! ```
+
+use std::error;
+use std::fmt;
+
+use ::bitvec::vec::BitVec;
+
+use super::LenientBool;
+
+/// A value that could not be parsed as a boolean.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidValue {
+    /// The index of the value in the input.
+    pub index: usize,
+    pub value: String,
+}
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid boolean value {:?} at index {}",
+            self.value, self.index
+        )
+    }
+}
+
+impl error::Error for InvalidValue {}
+
+/// Parses each value of `values` as a boolean, stopping at the first one that isn't
+/// recognized.
+pub fn parse_bitvec<I>(values: I) -> Result<BitVec, InvalidValue>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let values = values.into_iter();
+    let mut bits = BitVec::with_capacity(values.size_hint().0);
+    for (index, value) in values.enumerate() {
+        let value = value.as_ref();
+        match value.parse::<LenientBool>() {
+            Ok(b) => bits.push(b.0),
+            Err(_) => {
+                return Err(InvalidValue {
+                    index,
+                    value: value.to_owned(),
+                })
+            }
+        }
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod test {
+    use ::bitvec::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        let bits = parse_bitvec(vec!["yes".to_owned(), "F".to_owned(), "1".to_owned()]).unwrap();
+        assert_eq!(bits, bits![1, 0, 1]);
+        assert!(parse_bitvec(Vec::<&str>::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_many_values() {
+        let bits = parse_bitvec((0..1000).map(|i| if i % 3 == 0 { "t" } else { "n" })).unwrap();
+        assert_eq!(bits.len(), 1000);
+        assert_eq!(bits.count_ones(), 334);
+        assert!(bits[999]);
+    }
+
+    #[test]
+    fn parse_invalid_err() {
+        assert_eq!(
+            parse_bitvec(["t", "abc", "?"]),
+            Err(InvalidValue {
+                index: 1,
+                value: "abc".to_owned()
+            })
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Integration with `bpaf` argument parsing.
+//!
+//! The functions here build `bpaf` parsers that produce a `bool` from any value accepted by
+//! `LenientBool`.
+//!
+//! ```
+//! use bpaf::{long, Parser};
+//!
+//! let color = lenient_bool::bpaf::argument(long("color"), "BOOL").to_options();
+//! assert!(color.run_inner(&["--color", "yes"]).unwrap());
+//! ```
+//!
+//! With the derive API, take the value as a `String` and pass it through `parse`, as in
+//! `#[bpaf(argument("BOOL"), parse(lenient_bool::bpaf::parse))]`.
+
+use ::bpaf::{parsers::NamedArg, Parser};
+
+use super::{expected_message, LenientBool};
+
+/// Parses a value with the `LenientBool` rules, for use with `bpaf`'s `parse` combinator.
+pub fn parse(value: String) -> Result<bool, String> {
+    value
+        .parse::<LenientBool>()
+        .map(bool::from)
+        .map_err(|_| expected_message())
+}
+
+/// A named option that takes a lenient boolean value, e.g. `--color=yes`.
+pub fn argument(named: NamedArg, metavar: &'static str) -> impl Parser<bool> {
+    named.argument::<String>(metavar).parse(parse)
+}
+
+/// A positional argument that takes a lenient boolean value.
+pub fn positional(metavar: &'static str) -> impl Parser<bool> {
+    ::bpaf::positional::<String>(metavar).parse(parse)
+}
+
+#[cfg(test)]
+mod test {
+    use ::bpaf::{long, short, Parser};
+
+    use super::*;
+
+    #[test]
+    fn parse_yes() {
+        let p = argument(long("flag"), "BOOL").to_options();
+        assert!(p.run_inner(&["--flag=yes"]).unwrap());
+    }
+
+    #[test]
+    fn parse_0() {
+        let p = argument(short('f'), "BOOL").to_options();
+        assert!(!p.run_inner(&["-f", "0"]).unwrap());
+    }
+
+    #[test]
+    fn parse_positional() {
+        let p = positional("BOOL").to_options();
+        assert!(p.run_inner(&["T"]).unwrap());
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        let p = argument(long("flag"), "BOOL").to_options();
+        let err = p.run_inner(&["--flag", "abc"]).unwrap_err();
+        let message = err.unwrap_stderr();
+        // The list depends on which token and locale features are enabled, and bpaf wraps
+        // long messages.
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert!(message.contains(&expected_message()), "{}", message);
+    }
+}
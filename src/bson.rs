@@ -0,0 +1,108 @@
+//! Integration with `bson`.
+
+use ::bson::Bson;
+
+use super::{LenientBool, LenientBoolError};
+
+/// Converts a BSON value to a `LenientBool`.
+///
+/// Booleans convert directly. Numbers are `false` when zero and `true` otherwise, except
+/// that a NaN double is an error. Strings are parsed with the `LenientBool` rules. Any other
+/// kind of value is an error.
+impl<'a> TryFrom<&'a Bson> for LenientBool {
+    type Error = LenientBoolError;
+
+    fn try_from(value: &'a Bson) -> Result<Self, Self::Error> {
+        match *value {
+            Bson::Boolean(b) => Ok(LenientBool(b)),
+            Bson::Int32(i) => Ok(LenientBool(i != 0)),
+            Bson::Int64(i) => Ok(LenientBool(i != 0)),
+            Bson::Double(d) if !d.is_nan() => Ok(LenientBool(d != 0.0)),
+            Bson::String(ref s) => s.parse(),
+            _ => Err(LenientBoolError::new(&value.to_string())),
+        }
+    }
+}
+
+impl From<LenientBool> for Bson {
+    fn from(b: LenientBool) -> Bson {
+        Bson::Boolean(b.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_boolean() {
+        assert_eq!(
+            LenientBool::try_from(&Bson::Boolean(true)),
+            Ok(LenientBool(true))
+        );
+    }
+
+    #[test]
+    fn from_int32() {
+        assert_eq!(
+            LenientBool::try_from(&Bson::Int32(0)),
+            Ok(LenientBool(false))
+        );
+    }
+
+    #[test]
+    fn from_int64() {
+        assert_eq!(
+            LenientBool::try_from(&Bson::Int64(1)),
+            Ok(LenientBool(true))
+        );
+    }
+
+    #[test]
+    fn from_double() {
+        assert_eq!(
+            LenientBool::try_from(&Bson::Double(1.0)),
+            Ok(LenientBool(true))
+        );
+        assert_eq!(
+            LenientBool::try_from(&Bson::Double(0.0)),
+            Ok(LenientBool(false))
+        );
+    }
+
+    #[test]
+    fn from_double_nan_err() {
+        assert_eq!(
+            LenientBool::try_from(&Bson::Double(f64::NAN)),
+            Err(LenientBoolError::new("NaN"))
+        );
+    }
+
+    #[test]
+    fn from_string() {
+        let value = Bson::String("Yes".to_owned());
+        assert_eq!(LenientBool::try_from(&value), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn from_string_bad_input_err() {
+        let value = Bson::String("abc".to_owned());
+        assert_eq!(
+            LenientBool::try_from(&value),
+            Err(LenientBoolError::new("abc"))
+        );
+    }
+
+    #[test]
+    fn from_null_err() {
+        assert_eq!(
+            LenientBool::try_from(&Bson::Null),
+            Err(LenientBoolError::new("null"))
+        );
+    }
+
+    #[test]
+    fn into_bson() {
+        assert_eq!(Bson::from(LenientBool(false)), Bson::Boolean(false));
+    }
+}
@@ -0,0 +1,100 @@
+//! Converting spreadsheet cells read with `calamine` to booleans.
+//!
+//! Spreadsheets store checkbox columns in several ways, depending on the application and on
+//! how the sheet was edited, so `to_bool` follows spreadsheet conventions:
+//!
+//! * Boolean cells are used as they are.
+//! * Numeric cells are true when nonzero, as in Excel's `IF` and `AND`.
+//! * Text cells are parsed with the `LenientBool` rules, ignoring surrounding whitespace.
+//! * Empty cells, and text cells holding only whitespace, have no value.
+//!
+//! Other cells, such as dates and formula errors, are rejected.
+//!
+//! ```
+//! use calamine::Data;
+//! use lenient_bool::calamine::to_bool;
+//!
+//! assert_eq!(to_bool(&Data::Bool(true)), Ok(Some(true)));
+//! assert_eq!(to_bool(&Data::Float(0.0)), Ok(Some(false)));
+//! assert_eq!(to_bool(&Data::String(" Yes ".to_owned())), Ok(Some(true)));
+//! assert_eq!(to_bool(&Data::Empty), Ok(None));
+//! assert!(to_bool(&Data::String("maybe".to_owned())).is_err());
+//! ```
+
+use ::calamine::DataType;
+
+use super::{LenientBool, LenientBoolError};
+
+/// Converts a cell to a boolean, returning `None` for empty cells.
+pub fn to_bool<T: DataType>(cell: &T) -> Result<Option<bool>, LenientBoolError> {
+    if cell.is_empty() {
+        Ok(None)
+    } else if let Some(b) = cell.get_bool() {
+        Ok(Some(b))
+    } else if let Some(i) = cell.get_int() {
+        Ok(Some(i != 0))
+    } else if let Some(f) = cell.get_float() {
+        if f.is_nan() {
+            Err(LenientBoolError::new("NaN"))
+        } else {
+            Ok(Some(f != 0.0))
+        }
+    } else if let Some(s) = cell.get_string() {
+        let s = s.trim();
+        if s.is_empty() {
+            Ok(None)
+        } else {
+            s.parse::<LenientBool>().map(|b| Some(b.0))
+        }
+    } else {
+        Err(LenientBoolError::new(&cell.as_string().unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::calamine::{Data, DataRef};
+
+    use super::*;
+
+    #[test]
+    fn bool_cell() {
+        assert_eq!(to_bool(&Data::Bool(false)), Ok(Some(false)));
+    }
+
+    #[test]
+    fn int_cell() {
+        assert_eq!(to_bool(&Data::Int(2)), Ok(Some(true)));
+        assert_eq!(to_bool(&Data::Int(0)), Ok(Some(false)));
+    }
+
+    #[test]
+    fn float_cell() {
+        assert_eq!(to_bool(&Data::Float(1.0)), Ok(Some(true)));
+    }
+
+    #[test]
+    fn string_cell() {
+        assert_eq!(to_bool(&DataRef::SharedString("FALSE")), Ok(Some(false)));
+    }
+
+    #[test]
+    fn blank_string_cell() {
+        assert_eq!(to_bool(&Data::String("  ".to_owned())), Ok(None));
+    }
+
+    #[test]
+    fn empty_cell() {
+        assert_eq!(to_bool(&DataRef::Empty), Ok(None));
+    }
+
+    #[test]
+    fn bad_string_err() {
+        assert!(to_bool(&Data::String("abc".to_owned())).is_err());
+    }
+
+    #[test]
+    fn datetime_err() {
+        assert!(to_bool(&Data::DateTimeIso("2024-01-01".to_owned())).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+//! `chumsky` parsers for lenient booleans.
+//!
+//! Both parsers consume a single word, made of alphanumeric characters and underscores, and
+//! parse it with the `LenientBool` rules. They're labelled `boolean`, so errors from
+//! alternatives read naturally.
+//!
+//! `lenient_bool` fails when the word isn't a boolean, which lets it take part in `choice`
+//! and `or`. `lenient_bool_recovering` instead reports the bad word as an error and produces
+//! `false`, so the rest of the input is still parsed and every bad value is reported at once.
+//!
+//! ```
+//! use chumsky::prelude::*;
+//! use lenient_bool::chumsky::lenient_bool_recovering;
+//!
+//! let list = lenient_bool_recovering()
+//!     .separated_by(just(',').padded())
+//!     .collect::<Vec<_>>();
+//!
+//! let result = list.parse("yes, maybe, 0, perhaps");
+//! assert_eq!(result.output(), Some(&vec![true, false, false, false]));
+//! assert_eq!(result.errors().count(), 2);
+//! ```
+
+use ::chumsky::extra;
+use ::chumsky::prelude::*;
+
+use super::{expected_message, LenientBool};
+
+fn word<'src>() -> impl Parser<'src, &'src str, &'src str, extra::Err<Rich<'src, char>>> + Clone {
+    any()
+        .filter(|c: &char| c.is_alphanumeric() || *c == '_')
+        .repeated()
+        .at_least(1)
+        .to_slice()
+}
+
+/// Parses a boolean token, failing if the next word isn't one.
+pub fn lenient_bool<'src>(
+) -> impl Parser<'src, &'src str, bool, extra::Err<Rich<'src, char>>> + Clone {
+    word()
+        .try_map(|s: &str, span| {
+            s.parse::<LenientBool>()
+                .map(bool::from)
+                .map_err(|_| Rich::custom(span, expected_message()))
+        })
+        .labelled("boolean")
+}
+
+/// Parses a boolean token, reporting an error and producing `false` if the next word isn't
+/// one.
+pub fn lenient_bool_recovering<'src>(
+) -> impl Parser<'src, &'src str, bool, extra::Err<Rich<'src, char>>> + Clone {
+    word()
+        .validate(|s: &str, e, emitter| match s.parse::<LenientBool>() {
+            Ok(b) => b.0,
+            Err(_) => {
+                emitter.emit(Rich::custom(e.span(), expected_message()));
+                false
+            }
+        })
+        .labelled("boolean")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(lenient_bool().parse("yes").into_result(), Ok(true));
+    }
+
+    #[test]
+    fn parse_0() {
+        assert_eq!(lenient_bool().parse("0").into_result(), Ok(false));
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        let errors = lenient_bool().parse("abc").into_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span().into_range(), 0..3);
+    }
+
+    #[test]
+    fn parse_choice() {
+        let value = lenient_bool().map(Some).or(just("auto").to(None));
+        assert_eq!(value.parse("auto").into_result(), Ok(None));
+        assert_eq!(value.parse("N").into_result(), Ok(Some(false)));
+    }
+
+    #[test]
+    fn parse_recovering() {
+        let result = lenient_bool_recovering().parse("abc");
+        assert_eq!(result.output(), Some(&false));
+        assert_eq!(result.errors().count(), 1);
+    }
+}
@@ -0,0 +1,349 @@
+//! Integration with `clap` argument parsing.
+//!
+//! `LenientBoolValueParser` lets an argument take any value that `LenientBool` accepts, so
+//! `--flag=yes`, `--flag Y`, and `--flag=0` all work. Invalid values produce a regular clap
+//! error that lists the accepted values.
+//!
+//! ```
+//! use clap::{Arg, ArgAction, Command};
+//! use lenient_bool::clap::lenient_bool_value_parser;
+//!
+//! let cmd = Command::new("app").arg(
+//!     Arg::new("color")
+//!         .long("color")
+//!         .action(ArgAction::Set)
+//!         .value_parser(lenient_bool_value_parser()),
+//! );
+//!
+//! let matches = cmd.try_get_matches_from(["app", "--color=yes"]).unwrap();
+//! assert_eq!(matches.get_one::<bool>("color"), Some(&true));
+//! ```
+//!
+//! `LenientBoolValueParser::strictness` changes which values are accepted, for example to
+//! allow `--color on` with `Strictness::Extended`.
+//!
+//! With clap-derive, a `LenientBool` field picks up the parser automatically. A plain `bool`
+//! field needs `value_parser = lenient_bool_value_parser()` and `action = ArgAction::Set`,
+//! since clap otherwise treats `bool` fields as value-less switches.
+//!
+//! `lenient_flag` sets up a flag that may be given without a value, as in `--color` or
+//! `--color=no`, for a `LenientFlag`.
+
+use std::ffi::OsStr;
+
+use ::clap::builder::{MapValueParser, PossibleValue, TypedValueParser, ValueParserFactory};
+use ::clap::error::{ContextKind, ContextValue, ErrorKind};
+use ::clap::{Arg, ArgAction, Command, Error};
+
+use super::parser::with_current;
+use super::{LenientBool, LenientFlag, Strictness, EXTENDED_PACKS, FALSE_TOKENS, TRUE_TOKENS};
+
+/// A clap `TypedValueParser` that parses values with the `LenientBool` rules.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LenientBoolValueParser {
+    strictness: Strictness,
+}
+
+impl LenientBoolValueParser {
+    pub fn new() -> LenientBoolValueParser {
+        LenientBoolValueParser::default()
+    }
+
+    /// Parses values with `LenientBool::from_str_with` and the given `Strictness`.
+    ///
+    /// ```
+    /// use clap::{Arg, ArgAction, Command};
+    /// use lenient_bool::clap::LenientBoolValueParser;
+    /// use lenient_bool::Strictness;
+    ///
+    /// let cmd = Command::new("app").arg(
+    ///     Arg::new("color")
+    ///         .long("color")
+    ///         .action(ArgAction::Set)
+    ///         .value_parser(LenientBoolValueParser::new().strictness(Strictness::Extended)),
+    /// );
+    ///
+    /// let matches = cmd.try_get_matches_from(["app", "--color", "on"]).unwrap();
+    /// assert_eq!(matches.get_one::<bool>("color"), Some(&true));
+    /// ```
+    pub fn strictness(mut self, strictness: Strictness) -> LenientBoolValueParser {
+        self.strictness = strictness;
+        self
+    }
+
+    /// The accepted values, with the `true` values first.
+    ///
+    /// Apart from `Strictness::Strict`, values are parsed with `str::parse`, so these start
+    /// from the spellings of the parser set with `LenientBool::with_parser` or
+    /// `set_default_parser`, if there is one.
+    fn valid_values(&self) -> Vec<String> {
+        fn owned<'a>(tokens: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+            tokens.into_iter().map(str::to_owned).collect()
+        }
+        let (mut true_tokens, mut false_tokens) = match self.strictness {
+            Strictness::Strict => (owned(["true"]), owned(["false"])),
+            Strictness::Standard | Strictness::Extended => with_current(|parser| match parser {
+                Some(parser) => (
+                    owned(parser.possible_values_for(true)),
+                    owned(parser.possible_values_for(false)),
+                ),
+                None => (
+                    owned(TRUE_TOKENS.iter().copied()),
+                    owned(FALSE_TOKENS.iter().copied()),
+                ),
+            }),
+        };
+        if self.strictness == Strictness::Extended {
+            for pack in EXTENDED_PACKS {
+                for (tokens, pack_tokens) in [
+                    (&mut true_tokens, pack.true_tokens()),
+                    (&mut false_tokens, pack.false_tokens()),
+                ] {
+                    for token in pack_tokens {
+                        if !tokens.iter().any(|t| t == token) {
+                            tokens.push(token.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        true_tokens.extend(false_tokens);
+        true_tokens
+    }
+}
+
+/// Returns a value parser that yields a `bool`, for use with `Arg::value_parser`.
+pub fn lenient_bool_value_parser() -> LenientBoolValueParser {
+    LenientBoolValueParser::new()
+}
+
+impl TypedValueParser for LenientBoolValueParser {
+    type Value = bool;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<bool, Error> {
+        let s = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        let parsed = LenientBool::from_str_with(s, self.strictness).ok();
+        parsed.map(bool::from).ok_or_else(|| {
+            let mut err = Error::new(ErrorKind::InvalidValue).with_cmd(cmd);
+            let arg = arg
+                .map(|a| a.to_string())
+                .unwrap_or_else(|| "...".to_owned());
+            err.insert(ContextKind::InvalidArg, ContextValue::String(arg));
+            err.insert(
+                ContextKind::InvalidValue,
+                ContextValue::String(value.to_string_lossy().into_owned()),
+            );
+            err.insert(
+                ContextKind::ValidValue,
+                ContextValue::Strings(self.valid_values()),
+            );
+            err
+        })
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            self.valid_values().into_iter().map(PossibleValue::new),
+        ))
+    }
+}
+
+impl ValueParserFactory for LenientBool {
+    type Parser = MapValueParser<LenientBoolValueParser, fn(bool) -> LenientBool>;
+
+    fn value_parser() -> Self::Parser {
+        LenientBoolValueParser::new().map(LenientBool::from)
+    }
+}
+
+impl ValueParserFactory for LenientFlag {
+    type Parser = MapValueParser<LenientBoolValueParser, fn(bool) -> LenientFlag>;
+
+    fn value_parser() -> Self::Parser {
+        LenientBoolValueParser::new().map(LenientFlag::from)
+    }
+}
+
+/// Sets up `arg` as a `LenientFlag`: `false` when absent, `true` when given without a value,
+/// and parsed leniently when given one.
+///
+/// The value has to be attached with `=`, so that `--color input.txt` doesn't take
+/// `input.txt` as the value. `get_one::<LenientFlag>` always returns `Some`.
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use lenient_bool::clap::lenient_flag;
+/// use lenient_bool::LenientFlag;
+///
+/// let cmd = Command::new("app").arg(lenient_flag(Arg::new("color").long("color")));
+/// let color = |args: &[&str]| {
+///     let matches = cmd.clone().try_get_matches_from(args).unwrap();
+///     *matches.get_one::<LenientFlag>("color").unwrap()
+/// };
+/// assert_eq!(color(&["app"]), LenientFlag(false));
+/// assert_eq!(color(&["app", "--color"]), LenientFlag(true));
+/// assert_eq!(color(&["app", "--color=no"]), LenientFlag(false));
+/// ```
+///
+/// With clap-derive, a `LenientFlag` field needs the same settings:
+///
+/// ```text
+/// #[arg(long, num_args = 0..=1, require_equals = true,
+///       default_value = "false", default_missing_value = "true")]
+/// color: LenientFlag,
+/// ```
+pub fn lenient_flag(arg: Arg) -> Arg {
+    arg.action(ArgAction::Set)
+        .num_args(0..=1)
+        .require_equals(true)
+        .default_value("false")
+        .default_missing_value("true")
+        .value_parser(LenientFlag::value_parser())
+}
+
+#[cfg(test)]
+mod test {
+    use ::clap::error::ErrorKind;
+    use ::clap::{value_parser, Arg, ArgAction, Command};
+
+    use super::*;
+    use crate::LenientBoolParser;
+
+    fn command() -> Command {
+        Command::new("app").arg(
+            Arg::new("flag")
+                .long("flag")
+                .action(ArgAction::Set)
+                .value_parser(lenient_bool_value_parser()),
+        )
+    }
+
+    #[test]
+    fn parse_yes() {
+        let m = command()
+            .try_get_matches_from(["app", "--flag=yes"])
+            .unwrap();
+        assert_eq!(m.get_one::<bool>("flag"), Some(&true));
+    }
+
+    #[test]
+    fn parse_0() {
+        let m = command()
+            .try_get_matches_from(["app", "--flag", "0"])
+            .unwrap();
+        assert_eq!(m.get_one::<bool>("flag"), Some(&false));
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        let err = command()
+            .try_get_matches_from(["app", "--flag=abc"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(
+            err.get(ContextKind::InvalidValue),
+            Some(&ContextValue::String("abc".to_owned()))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn invalid_utf8_err() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let value = OsStr::from_bytes(b"\xffyes");
+        let err = lenient_bool_value_parser()
+            .parse_ref(&Command::new("app"), None, value)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidUtf8);
+    }
+
+    #[test]
+    fn error_lists_valid_values() {
+        let err = command()
+            .try_get_matches_from(["app", "--flag=abc"])
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("possible values: true, t, yes"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn valid_values_follow_current_parser() {
+        let (possible, message) = LenientBool::with_parser(LenientBoolParser::go(), || {
+            let possible: Vec<String> = lenient_bool_value_parser()
+                .possible_values()
+                .unwrap()
+                .map(|v| v.get_name().to_owned())
+                .collect();
+            let err = command()
+                .try_get_matches_from(["app", "--flag=yes"])
+                .unwrap_err();
+            (possible, err.to_string())
+        });
+        assert!(possible.iter().any(|v| v == "True"), "{:?}", possible);
+        assert!(!possible.iter().any(|v| v == "yes"), "{:?}", possible);
+        assert!(
+            message.contains("possible values: 1, t, T, TRUE"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn strictness() {
+        let command = |strictness| {
+            Command::new("app").arg(
+                Arg::new("flag")
+                    .long("flag")
+                    .action(ArgAction::Set)
+                    .value_parser(LenientBoolValueParser::new().strictness(strictness)),
+            )
+        };
+        let m = command(Strictness::Extended)
+            .try_get_matches_from(["app", "--flag", "off"])
+            .unwrap();
+        assert_eq!(m.get_one::<bool>("flag"), Some(&false));
+        assert!(command(Strictness::Strict)
+            .try_get_matches_from(["app", "--flag", "yes"])
+            .is_err());
+    }
+
+    fn flag(args: &[&str]) -> Result<LenientFlag, Error> {
+        let cmd = Command::new("app")
+            .arg(lenient_flag(Arg::new("flag").long("flag")))
+            .arg(Arg::new("input"));
+        let matches = cmd.try_get_matches_from(args)?;
+        Ok(*matches.get_one::<LenientFlag>("flag").unwrap())
+    }
+
+    #[test]
+    fn lenient_flag_values() {
+        assert_eq!(flag(&["app"]).unwrap(), LenientFlag(false));
+        assert_eq!(flag(&["app", "--flag"]).unwrap(), LenientFlag(true));
+        assert_eq!(flag(&["app", "--flag=Y"]).unwrap(), LenientFlag(true));
+        assert!(flag(&["app", "--flag=off"]).is_err());
+        assert_eq!(flag(&["app", "--flag=0"]).unwrap(), LenientFlag(false));
+    }
+
+    #[test]
+    fn lenient_flag_needs_equals() {
+        assert_eq!(flag(&["app", "--flag", "no"]).unwrap(), LenientFlag(true));
+    }
+
+    #[test]
+    fn value_parser_factory() {
+        let cmd = Command::new("app").arg(
+            Arg::new("flag")
+                .long("flag")
+                .value_parser(value_parser!(LenientBool)),
+        );
+        let m = cmd.try_get_matches_from(["app", "--flag=T"]).unwrap();
+        assert_eq!(m.get_one::<LenientBool>("flag"), Some(&LenientBool(true)));
+    }
+}
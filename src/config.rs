@@ -0,0 +1,156 @@
+//! Integration with the `config` crate.
+//!
+//! Values from environment variables and some file formats reach `config` as strings, and
+//! `Config::get_bool` only understands a few spellings of them. `get_bool` here accepts
+//! anything `LenientBool` does, and `try_deserialize` reads every `bool` of a whole
+//! configuration struct that way.
+//!
+//! ```
+//! use config::Config;
+//!
+//! let config = Config::builder()
+//!     .set_override("debug", "Y")
+//!     .unwrap()
+//!     .build()
+//!     .unwrap();
+//! assert!(lenient_bool::config::get_bool(&config, "debug").unwrap());
+//! ```
+
+use ::config::{Config, ConfigError, Value, ValueKind};
+use ::serde::de::DeserializeOwned;
+
+use super::{LenientBool, LenientBoolDeserializer};
+
+/// Converts a configuration value to a `bool`.
+///
+/// Booleans convert directly, integers must be `0` or `1`, and strings are parsed with the
+/// `LenientBool` rules. Any other value returns `None`.
+pub fn to_bool(value: &Value) -> Option<bool> {
+    match value.kind {
+        ValueKind::Boolean(b) => Some(b),
+        ValueKind::I64(i) => int_to_bool(i.into()),
+        ValueKind::I128(i) => int_to_bool(i),
+        ValueKind::U64(i) => int_to_bool(i.into()),
+        ValueKind::U128(i) => i128::try_from(i).ok().and_then(int_to_bool),
+        ValueKind::String(ref s) => s.parse::<LenientBool>().ok().map(bool::from),
+        _ => None,
+    }
+}
+
+fn int_to_bool(i: i128) -> Option<bool> {
+    match i {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
+/// Looks up `key` in `config` and converts it to a `bool` with `to_bool`.
+pub fn get_bool(config: &Config, key: &str) -> Result<bool, ConfigError> {
+    let value = config.get::<Value>(key)?;
+    to_bool(&value).ok_or_else(|| {
+        let message = match value.origin() {
+            Some(origin) => format!("invalid boolean value for key `{}` in {}", key, origin),
+            None => format!("invalid boolean value for key `{}`", key),
+        };
+        ConfigError::Message(message)
+    })
+}
+
+/// Deserializes `config` into `T`, like `Config::try_deserialize`, but with every `bool`
+/// read with the `LenientBool` rules through `LenientBoolDeserializer`.
+///
+/// This suits layered configuration where environment variables and command-line overrides
+/// supply booleans as strings.
+///
+/// ```
+/// use config::Config;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Settings {
+///     debug: bool,
+///     cache: Option<bool>,
+/// }
+///
+/// let config = Config::builder()
+///     .set_override("debug", "Y")
+///     .unwrap()
+///     .set_override("cache", "0")
+///     .unwrap()
+///     .build()
+///     .unwrap();
+/// assert!(config.clone().try_deserialize::<Settings>().is_err());
+///
+/// let settings: Settings = lenient_bool::config::try_deserialize(config).unwrap();
+/// assert!(settings.debug);
+/// assert_eq!(settings.cache, Some(false));
+/// ```
+pub fn try_deserialize<T: DeserializeOwned>(config: Config) -> Result<T, ConfigError> {
+    T::deserialize(LenientBoolDeserializer::new(config))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn config<T: Into<ValueKind>>(value: T) -> Config {
+        Config::builder()
+            .set_override("flag", value)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn string_yes() {
+        assert!(get_bool(&config("yes"), "flag").unwrap());
+    }
+
+    #[test]
+    fn string_f() {
+        assert!(!get_bool(&config("f"), "flag").unwrap());
+    }
+
+    #[test]
+    fn boolean() {
+        assert!(get_bool(&config(true), "flag").unwrap());
+    }
+
+    #[test]
+    fn integer() {
+        assert!(!get_bool(&config(0), "flag").unwrap());
+        assert!(get_bool(&config(2), "flag").is_err());
+    }
+
+    #[test]
+    fn bad_input_err() {
+        let err = get_bool(&config("abc"), "flag").unwrap_err();
+        assert_eq!(err.to_string(), "invalid boolean value for key `flag`");
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Settings {
+        flag: bool,
+        #[serde(default)]
+        other: bool,
+    }
+
+    #[test]
+    fn deserialize_struct() {
+        let settings: Settings = try_deserialize(config("yes")).unwrap();
+        assert!(settings.flag);
+        assert!(!settings.other);
+    }
+
+    #[test]
+    fn deserialize_bad_input_err() {
+        assert!(try_deserialize::<Settings>(config("abc")).is_err());
+    }
+
+    #[test]
+    fn missing_err() {
+        let err = get_bool(&config("yes"), "other").unwrap_err();
+        assert!(matches!(err, ConfigError::NotFound(_)));
+    }
+}
@@ -0,0 +1,253 @@
+//! Reading boolean columns with the `csv` crate.
+//!
+//! When records are deserialized with serde, fields can simply be declared as `LenientBool`,
+//! or `Option<LenientBool>` to treat empty fields as missing:
+//!
+//! ```
+//! use lenient_bool::LenientBool;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Row {
+//!     name: String,
+//!     active: LenientBool,
+//!     verified: Option<LenientBool>,
+//! }
+//!
+//! let data = "name,active,verified\nalice,Y,\nbob,no,1\n";
+//! let mut reader = csv::Reader::from_reader(data.as_bytes());
+//! let rows = reader
+//!     .deserialize::<Row>()
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//! assert!(*rows[0].active);
+//! assert_eq!(rows[0].verified, None);
+//! assert_eq!(rows[1].verified, Some(LenientBool(true)));
+//! ```
+//!
+//! For untyped records, `bool_columns` picks out just the boolean columns and reports the
+//! location of any value that fails to parse:
+//!
+//! ```
+//! let data = "name,active,admin\nalice,Y,no\nbob,maybe,no\n";
+//! let mut reader = csv::Reader::from_reader(data.as_bytes());
+//! let mut rows = lenient_bool::csv::bool_columns(&mut reader, ["active", "admin"]).unwrap();
+//!
+//! assert_eq!(rows.next().unwrap().unwrap(), vec![true, false]);
+//!
+//! let err = rows.next().unwrap().unwrap_err();
+//! assert_eq!(err.to_string(), "invalid boolean value \"maybe\" at line 3, column 2");
+//! ```
+
+use std::error;
+use std::fmt;
+use std::io;
+
+use ::csv::{Reader, StringRecord, StringRecordsIter};
+
+use super::LenientBool;
+
+/// A column in a CSV file, identified by its index or by its header name.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Column<'a> {
+    Index(usize),
+    Name(&'a str),
+}
+
+impl<'a> From<usize> for Column<'a> {
+    fn from(index: usize) -> Column<'a> {
+        Column::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for Column<'a> {
+    fn from(name: &'a str) -> Column<'a> {
+        Column::Name(name)
+    }
+}
+
+/// An error from reading boolean columns.
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying reader failed.
+    Csv(::csv::Error),
+    /// A column name was not found in the header row.
+    MissingColumn(String),
+    /// A record was missing a column.
+    MissingField { line: u64, column: usize },
+    /// A value could not be parsed as a boolean.
+    InvalidValue {
+        line: u64,
+        column: usize,
+        value: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Csv(e) => e.fmt(f),
+            Error::MissingColumn(name) => write!(f, "no column named {:?}", name),
+            Error::MissingField { line, column } => {
+                write!(f, "missing column {} at line {}", column, line)
+            }
+            Error::InvalidValue {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "invalid boolean value {:?} at line {}, column {}",
+                value, line, column
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Csv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<::csv::Error> for Error {
+    fn from(e: ::csv::Error) -> Error {
+        Error::Csv(e)
+    }
+}
+
+/// An iterator over the selected boolean columns of each record, created by `bool_columns`.
+pub struct BoolColumns<'r, R: 'r> {
+    records: StringRecordsIter<'r, R>,
+    indexes: Vec<usize>,
+}
+
+/// Returns an iterator that parses the given columns of each remaining record.
+///
+/// Each item holds the parsed values in the order the columns were given. Columns named by
+/// header require the reader to have headers. Lines and columns in errors are 1-based, and
+/// lines count the header row.
+pub fn bool_columns<'r, 'c, R, I>(
+    reader: &'r mut Reader<R>,
+    columns: I,
+) -> Result<BoolColumns<'r, R>, Error>
+where
+    R: io::Read,
+    I: IntoIterator,
+    I::Item: Into<Column<'c>>,
+{
+    let mut headers: Option<StringRecord> = None;
+    let mut indexes = Vec::new();
+    for column in columns {
+        let index = match column.into() {
+            Column::Index(i) => i,
+            Column::Name(name) => {
+                if headers.is_none() {
+                    headers = Some(reader.headers()?.clone());
+                }
+                headers
+                    .iter()
+                    .flat_map(|h| h.iter())
+                    .position(|h| h == name)
+                    .ok_or_else(|| Error::MissingColumn(name.to_owned()))?
+            }
+        };
+        indexes.push(index);
+    }
+
+    Ok(BoolColumns {
+        records: reader.records(),
+        indexes,
+    })
+}
+
+impl<'r, R: io::Read> Iterator for BoolColumns<'r, R> {
+    type Item = Result<Vec<bool>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let line = record.position().map(|p| p.line()).unwrap_or(0);
+
+        let values = self.indexes.iter().map(|&index| {
+            let column = index + 1;
+            let value = record
+                .get(index)
+                .ok_or(Error::MissingField { line, column })?;
+            value
+                .parse::<LenientBool>()
+                .map(bool::from)
+                .map_err(|_| Error::InvalidValue {
+                    line,
+                    column,
+                    value: value.to_owned(),
+                })
+        });
+        Some(values.collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: &str = "name,active,admin\nalice,Y,no\nbob,0,TRUE\n";
+
+    fn read<'c, C: Into<Column<'c>> + Copy>(
+        data: &str,
+        columns: &[C],
+    ) -> Result<Vec<Vec<bool>>, Error> {
+        let mut reader = Reader::from_reader(data.as_bytes());
+        let columns = columns.iter().map(|&c| c.into());
+        bool_columns(&mut reader, columns)?.collect()
+    }
+
+    #[test]
+    fn by_name() {
+        let rows = read(DATA, &["admin", "active"]).unwrap();
+        assert_eq!(rows, vec![vec![false, true], vec![true, false]]);
+    }
+
+    #[test]
+    fn by_index() {
+        let rows = read(DATA, &[1]).unwrap();
+        assert_eq!(rows, vec![vec![true], vec![false]]);
+    }
+
+    #[test]
+    fn missing_column_err() {
+        match read(DATA, &["deleted"]) {
+            Err(Error::MissingColumn(name)) => assert_eq!(name, "deleted"),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn missing_field_err() {
+        match read(DATA, &[5]) {
+            Err(Error::MissingField { line, column }) => assert_eq!((line, column), (2, 6)),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn invalid_value_err() {
+        let data = "name,active\nalice,yes\nbob,maybe\n";
+        match read(data, &["active"]) {
+            Err(Error::InvalidValue {
+                line,
+                column,
+                value,
+            }) => {
+                assert_eq!((line, column), (3, 2));
+                assert_eq!(value, "maybe");
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,389 @@
+//! A `Deserializer` adapter that reads every `bool` with the `LenientBool` rules.
+
+use std::fmt;
+
+use ::serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use ::serde::Deserialize;
+
+use super::LenientBool;
+
+/// Wraps another `Deserializer` so that every `bool` in the document is read leniently,
+/// without annotating each field.
+///
+/// Whenever the type being deserialized asks for a `bool`, including inside `Option`s,
+/// sequences, maps, and enums, the value is parsed like a `LenientBool`. Everything else is
+/// passed through to the wrapped deserializer unchanged.
+///
+/// ```
+/// use lenient_bool::LenientBoolDeserializer;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     verbose: bool,
+///     features: Vec<bool>,
+///     color: Option<bool>,
+///     name: String,
+/// }
+///
+/// let json = r#"{ "verbose": "yes", "features": [1, "N", true], "color": "f", "name": "no" }"#;
+/// let mut json = serde_json::Deserializer::from_str(json);
+/// let config = Config::deserialize(LenientBoolDeserializer::new(&mut json)).unwrap();
+/// assert!(config.verbose);
+/// assert_eq!(config.features, [true, false, true]);
+/// assert_eq!(config.color, Some(false));
+/// assert_eq!(config.name, "no");
+/// ```
+///
+/// serde buffers the contents of `#[serde(flatten)]` fields and untagged enums before
+/// deserializing them, so `bool`s inside those are read strictly.
+#[derive(Debug, Clone, Copy)]
+pub struct LenientBoolDeserializer<D> {
+    inner: D,
+}
+
+impl<D> LenientBoolDeserializer<D> {
+    pub fn new(inner: D) -> LenientBoolDeserializer<D> {
+        LenientBoolDeserializer { inner }
+    }
+
+    /// Returns the wrapped deserializer.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+/// Wraps the visitors, seeds, and accessors handed to the inner deserializer, so that the
+/// deserializers they pass on are wrapped too.
+struct Wrap<T>(T);
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner.$method($($arg,)* Wrap(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for LenientBoolDeserializer<D> {
+    type Error = D::Error;
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_bool(LenientBool::deserialize(self.inner)?.0)
+    }
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<V::Value, E>
+            where
+                E: ::serde::de::Error,
+            {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Wrap<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_none<E>(self) -> Result<V::Value, E>
+    where
+        E: ::serde::de::Error,
+    {
+        self.0.visit_none()
+    }
+
+    fn visit_unit<E>(self) -> Result<V::Value, E>
+    where
+        E: ::serde::de::Error,
+    {
+        self.0.visit_unit()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .visit_some(LenientBoolDeserializer::new(deserializer))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .visit_newtype_struct(LenientBoolDeserializer::new(deserializer))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<V::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.0.visit_seq(Wrap(seq))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<V::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.0.visit_map(Wrap(map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<V::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.0.visit_enum(Wrap(data))
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Wrap<S> {
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<S::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .deserialize(LenientBoolDeserializer::new(deserializer))
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, A::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.next_element_seed(Wrap(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.0.next_key_seed(Wrap(seed))
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value, A::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.next_value_seed(Wrap(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+    type Variant = Wrap<A::Variant>;
+
+    fn variant_seed<T>(self, seed: T) -> Result<(T::Value, Wrap<A::Variant>), A::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0
+            .variant_seed(Wrap(seed))
+            .map(|(value, variant)| (value, Wrap(variant)))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.0.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, A::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.newtype_variant_seed(Wrap(seed))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.tuple_variant(len, Wrap(visitor))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.struct_variant(fields, Wrap(visitor))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    fn from_json<'a, T: Deserialize<'a>>(json: &'a str) -> Result<T, serde_json::Error> {
+        let mut json = serde_json::Deserializer::from_str(json);
+        T::deserialize(LenientBoolDeserializer::new(&mut json))
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Switch {
+        Unit,
+        Newtype(bool),
+        Tuple(bool, u8),
+        Struct { on: bool },
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Wrapper(bool);
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Nested {
+        flags: BTreeMap<String, bool>,
+        maybe: Option<bool>,
+        wrapper: Wrapper,
+        switches: Vec<Switch>,
+    }
+
+    #[test]
+    fn top_level_bool() {
+        assert!(from_json::<bool>(r#""Y""#).unwrap());
+        assert!(!from_json::<bool>("0").unwrap());
+    }
+
+    #[test]
+    fn nested_bools() {
+        let json = r#"{
+            "flags": { "a": "yes", "b": 0 },
+            "maybe": "T",
+            "wrapper": "n",
+            "switches": ["Unit", { "Newtype": "1" }, { "Tuple": ["f", 7] }, { "Struct": { "on": "YES" } }]
+        }"#;
+        let nested: Nested = from_json(json).unwrap();
+        assert_eq!(
+            nested,
+            Nested {
+                flags: BTreeMap::from([("a".to_owned(), true), ("b".to_owned(), false)]),
+                maybe: Some(true),
+                wrapper: Wrapper(false),
+                switches: vec![
+                    Switch::Unit,
+                    Switch::Newtype(true),
+                    Switch::Tuple(false, 7),
+                    Switch::Struct { on: true },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn other_types_unchanged() {
+        assert_eq!(from_json::<String>(r#""yes""#).unwrap(), "yes");
+        assert_eq!(from_json::<Option<bool>>("null").unwrap(), None);
+        assert!(from_json::<u8>(r#""1""#).is_err());
+    }
+
+    #[test]
+    fn invalid_bool_err() {
+        let err = from_json::<Vec<bool>>(r#"[true, "maybe"]"#).unwrap_err();
+        assert!(err.to_string().contains("maybe"), "{}", err);
+    }
+}
@@ -0,0 +1,232 @@
+//! Deciding whether a column of values holds booleans, for schema inference in CSV
+//! importers and similar tools, and summarizing what a column contained after parsing it.
+//!
+//! ```
+//! use lenient_bool::detect::looks_boolean;
+//!
+//! let report = looks_boolean(["Y", "N", "", "y", "n"]);
+//! assert_eq!(report.sampled, 4);
+//! assert_eq!(report.fraction(), 1.0);
+//! assert!(report.confidence > 0.9);
+//!
+//! assert!(looks_boolean(["3", "17", "0"]).confidence < 0.5);
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{LenientBool, LenientBoolError, LenientOptionBool};
+
+/// The most values that `looks_boolean` looks at.
+pub const SAMPLE_SIZE: usize = 1000;
+
+/// What `looks_boolean` found in a column.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ColumnReport {
+    /// The number of non-empty values looked at.
+    pub sampled: usize,
+    /// The number of empty or all-whitespace values, which are skipped.
+    pub empty: usize,
+    /// The number of values that parsed as `true`.
+    pub trues: usize,
+    /// The number of values that parsed as `false`.
+    pub falses: usize,
+    /// The distinct values that parsed, in lowercase.
+    pub tokens: BTreeSet<String>,
+    /// How likely the column is to be boolean, from 0 to 1.
+    ///
+    /// This is the fraction of values that parsed, reduced by a quarter if only one of
+    /// `true` and `false` appeared, and by another quarter if every value was a digit, since
+    /// such a column may just as well hold integers.
+    pub confidence: f64,
+}
+
+impl ColumnReport {
+    /// The fraction of sampled values that parsed, or 0 if none were sampled.
+    pub fn fraction(&self) -> f64 {
+        if self.sampled == 0 {
+            0.0
+        } else {
+            (self.trues + self.falses) as f64 / self.sampled as f64
+        }
+    }
+}
+
+/// Looks at up to `SAMPLE_SIZE` values and reports how many parse as lenient booleans.
+///
+/// Whitespace around values is ignored, and empty values are counted separately rather than
+/// as failures, since they usually mean a missing value.
+pub fn looks_boolean<'a>(values: impl IntoIterator<Item = &'a str>) -> ColumnReport {
+    let mut report = ColumnReport::default();
+    for value in values.into_iter().take(SAMPLE_SIZE) {
+        let value = value.trim();
+        if value.is_empty() {
+            report.empty += 1;
+            continue;
+        }
+
+        report.sampled += 1;
+        if let Ok(b) = value.parse::<LenientBool>() {
+            if b.0 {
+                report.trues += 1;
+            } else {
+                report.falses += 1;
+            }
+            report.tokens.insert(value.to_lowercase());
+        }
+    }
+
+    let mut confidence = report.fraction();
+    if report.trues == 0 || report.falses == 0 {
+        confidence *= 0.75;
+    }
+    if !report.tokens.is_empty()
+        && report
+            .tokens
+            .iter()
+            .all(|t| t.bytes().all(|b| b.is_ascii_digit()))
+    {
+        confidence *= 0.75;
+    }
+    report.confidence = confidence;
+    report
+}
+
+/// Counts of the values seen while parsing a column, for data cleaning.
+///
+/// Values are parsed with the `LenientOptionBool` rules, so the empty string and null markers
+/// such as `n/a` are counted as nulls rather than as unparseable.
+///
+/// ```
+/// use lenient_bool::detect::BoolStats;
+///
+/// let mut stats = BoolStats::new();
+/// for value in ["yes", "no", "N/A", "yes", "maybe", "Maybe", "?"] {
+///     let _ = stats.observe(value);
+/// }
+/// assert_eq!((stats.trues, stats.falses, stats.nulls, stats.invalid), (2, 1, 1, 3));
+/// assert_eq!(stats.unknown.get("maybe"), Some(&1));
+/// assert_eq!(stats.unknown.len(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BoolStats {
+    /// The number of values that parsed as `true`.
+    pub trues: usize,
+    /// The number of values that parsed as `false`.
+    pub falses: usize,
+    /// The number of empty values and null markers.
+    pub nulls: usize,
+    /// The number of values that didn't parse.
+    pub invalid: usize,
+    /// How many times each distinct unparseable value appeared.
+    ///
+    /// At most `BoolStats::MAX_UNKNOWN` distinct values are kept. Any others are still
+    /// counted in `invalid`.
+    pub unknown: BTreeMap<String, usize>,
+}
+
+impl BoolStats {
+    /// The most distinct unparseable values that are kept in `unknown`.
+    pub const MAX_UNKNOWN: usize = 1000;
+
+    pub fn new() -> BoolStats {
+        BoolStats::default()
+    }
+
+    /// Parses `s` and counts the result, which is also returned.
+    pub fn observe(&mut self, s: &str) -> Result<Option<bool>, LenientBoolError> {
+        let result = s.parse::<LenientOptionBool>().map(Option::from);
+        match result {
+            Ok(Some(true)) => self.trues += 1,
+            Ok(Some(false)) => self.falses += 1,
+            Ok(None) => self.nulls += 1,
+            Err(_) => {
+                self.invalid += 1;
+                if let Some(count) = self.unknown.get_mut(s) {
+                    *count += 1;
+                } else if self.unknown.len() < BoolStats::MAX_UNKNOWN {
+                    self.unknown.insert(s.to_owned(), 1);
+                }
+            }
+        }
+        result
+    }
+
+    /// The total number of values observed.
+    pub fn total(&self) -> usize {
+        self.trues + self.falses + self.nulls + self.invalid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn boolean_column() {
+        let report = looks_boolean(["yes", "No", " YES ", "no"]);
+        assert_eq!(report.sampled, 4);
+        assert_eq!((report.trues, report.falses), (2, 2));
+        assert_eq!(report.tokens.iter().collect::<Vec<_>>(), ["no", "yes"]);
+        assert_eq!(report.confidence, 1.0);
+    }
+
+    #[test]
+    fn mixed_column() {
+        let report = looks_boolean(["true", "false", "maybe", "true"]);
+        assert_eq!(report.fraction(), 0.75);
+        assert_eq!(report.confidence, 0.75);
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-single-letter"))]
+    fn one_value_and_digits() {
+        assert_eq!(looks_boolean(["t", "T"]).confidence, 0.75);
+        assert_eq!(looks_boolean(["1", "0"]).confidence, 0.75);
+        assert_eq!(looks_boolean(["1", "1"]).confidence, 0.5625);
+    }
+
+    #[test]
+    fn empty_column() {
+        let report = looks_boolean(["", "  "]);
+        assert_eq!((report.sampled, report.empty), (0, 2));
+        assert_eq!(report.confidence, 0.0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-single-letter"))]
+    fn stats_counts() {
+        let mut stats = BoolStats::new();
+        assert_eq!(stats.observe("T"), Ok(Some(true)));
+        assert_eq!(stats.observe(""), Ok(None));
+        assert_eq!(stats.observe("abc"), Err(LenientBoolError::new("abc")));
+        let _ = stats.observe("abc");
+        let _ = stats.observe("0");
+        assert_eq!(stats.total(), 5);
+        assert_eq!(
+            (stats.trues, stats.falses, stats.nulls, stats.invalid),
+            (1, 1, 1, 2)
+        );
+        assert_eq!(
+            stats.unknown.into_iter().collect::<Vec<_>>(),
+            [("abc".to_owned(), 2)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-numeric")]
+    fn stats_unknown_limited() {
+        let mut stats = BoolStats::new();
+        for i in 0..BoolStats::MAX_UNKNOWN + 5 {
+            let _ = stats.observe(&i.to_string());
+        }
+        assert_eq!(stats.unknown.len(), BoolStats::MAX_UNKNOWN);
+        assert_eq!(stats.invalid, BoolStats::MAX_UNKNOWN + 3);
+    }
+
+    #[test]
+    fn samples_limited() {
+        let values = vec!["y"; SAMPLE_SIZE + 10];
+        assert_eq!(looks_boolean(values).sampled, SAMPLE_SIZE);
+    }
+}
@@ -0,0 +1,162 @@
+//! Integration with Diesel.
+//!
+//! `LenientBool` can be used for both `Bool` and `Text` columns. Reading from a `Text` column
+//! parses the value with the `LenientBool` rules, and writing to one stores `true` or `false`.
+//! The implementations are generic over the backend, so they work with any backend that
+//! supports `bool` and `String`.
+//!
+//! Schemas that store flags as `'Y'`/`'N'` or `'1'`/`'0'` can be read directly. To write
+//! those forms back, write the string from `LenientBool::format_as` with the matching
+//! `BoolStyle` instead.
+
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::{Bool, Text};
+
+use super::LenientBool;
+
+impl<DB> FromSql<Bool, DB> for LenientBool
+where
+    DB: Backend,
+    bool: FromSql<Bool, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        bool::from_sql(bytes).map(LenientBool)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for LenientBool
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let s = String::from_sql(bytes)?;
+        s.parse().map_err(|e| Box::new(e) as _)
+    }
+}
+
+impl<DB> ToSql<Bool, DB> for LenientBool
+where
+    DB: Backend,
+    bool: ToSql<Bool, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+impl<DB> ToSql<Text, DB> for LenientBool
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::diesel::prelude::*;
+    use ::diesel::sql_query;
+    use ::diesel::sqlite::SqliteConnection;
+
+    use super::*;
+    use crate::LenientBoolError;
+
+    ::diesel::table! {
+        flags (id) {
+            id -> Integer,
+            enabled -> Bool,
+            legacy -> Text,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = flags)]
+    struct Flag {
+        id: i32,
+        enabled: LenientBool,
+        legacy: LenientBool,
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        sql_query("CREATE TABLE flags (id INTEGER PRIMARY KEY, enabled BOOLEAN NOT NULL, legacy TEXT NOT NULL)")
+            .execute(&mut conn)
+            .unwrap();
+        conn
+    }
+
+    fn load_legacy(conn: &mut SqliteConnection, value: &str) -> QueryResult<LenientBool> {
+        sql_query(format!("INSERT INTO flags VALUES (1, 1, '{}')", value))
+            .execute(conn)
+            .unwrap();
+        flags::table.select(flags::legacy).first(conn)
+    }
+
+    #[test]
+    fn text_yes() {
+        let mut conn = connection();
+        assert_eq!(load_legacy(&mut conn, "yes"), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn text_n_upper() {
+        let mut conn = connection();
+        assert_eq!(load_legacy(&mut conn, "N"), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn text_numeric() {
+        let mut conn = connection();
+        assert_eq!(load_legacy(&mut conn, "0"), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn text_bad_input_err() {
+        let mut conn = connection();
+        let err = load_legacy(&mut conn, "treu").unwrap_err();
+        let mut source = std::error::Error::source(&err);
+        while let Some(e) = source {
+            if let Some(e) = e.downcast_ref::<LenientBoolError>() {
+                assert_eq!(e.suggestion(), Some("true"));
+                return;
+            }
+            source = e.source();
+        }
+        panic!("no LenientBoolError in {:?}", err);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut conn = connection();
+        let flag = Flag {
+            id: 1,
+            enabled: LenientBool(true),
+            legacy: LenientBool(false),
+        };
+        ::diesel::insert_into(flags::table)
+            .values(&flag)
+            .execute(&mut conn)
+            .unwrap();
+
+        let legacy: String = sql_query("SELECT legacy FROM flags")
+            .load::<Legacy>(&mut conn)
+            .unwrap()
+            .remove(0)
+            .legacy;
+        assert_eq!(legacy, "false");
+
+        let loaded: Flag = flags::table.first(&mut conn).unwrap();
+        assert_eq!(loaded, flag);
+    }
+
+    #[derive(QueryableByName)]
+    struct Legacy {
+        #[diesel(sql_type = Text)]
+        legacy: String,
+    }
+}
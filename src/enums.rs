@@ -0,0 +1,133 @@
+//! Matching strings against a table of spellings, for enums with lenient parsing like
+//! `LenientBool`'s.
+
+use core::fmt;
+
+use super::{eq_ignore_case, LenientBoolError};
+
+/// Matches `s` against a table of spellings, ignoring case the same way `LenientBool` does,
+/// and returns the value of the first spelling that matches.
+///
+/// This is what `#[derive(LenientFromStr)]` uses, and it can be called directly for types
+/// that implement `FromStr` by hand.
+///
+/// ```
+/// let table = [("on", Some(true)), ("off", Some(false)), ("auto", None)];
+/// assert_eq!(lenient_bool::match_token("AUTO", &table), Some(None));
+/// assert_eq!(lenient_bool::match_token("On", &table), Some(Some(true)));
+/// assert_eq!(lenient_bool::match_token("maybe", &table), None);
+/// ```
+pub fn match_token<T: Copy>(s: &str, table: &[(&str, T)]) -> Option<T> {
+    table
+        .iter()
+        .find(|(token, _)| eq_ignore_case(s, token))
+        .map(|(_, value)| *value)
+}
+
+/// The error returned when a string doesn't match any spelling of an enum that derives
+/// `LenientFromStr`.
+///
+/// Like `LenientBoolError`, it keeps a copy of the input, truncated to
+/// `LenientBoolError::MAX_INPUT_LEN` bytes.
+///
+/// ```
+/// use lenient_bool::ParseEnumError;
+///
+/// let err = ParseEnumError::new("Mode", "sometimes");
+/// assert_eq!(err.input(), "sometimes");
+/// assert_eq!(err.to_string(), r#"invalid Mode value "sometimes""#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseEnumError {
+    type_name: &'static str,
+    input: LenientBoolError,
+}
+
+impl ParseEnumError {
+    /// Creates an error for `input`, which failed to parse as the type named `type_name`.
+    pub fn new(type_name: &'static str, input: &str) -> ParseEnumError {
+        ParseEnumError {
+            type_name,
+            input: LenientBoolError::new(input),
+        }
+    }
+
+    /// The name of the type that the input failed to parse as.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The input that failed to parse, truncated if it was longer than
+    /// `LenientBoolError::MAX_INPUT_LEN`.
+    pub fn input(&self) -> &str {
+        self.input.input()
+    }
+
+    /// Whether `input` was cut short.
+    pub fn is_truncated(&self) -> bool {
+        self.input.is_truncated()
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid {} value {:?}", self.type_name, self.input())?;
+        if self.is_truncated() {
+            f.write_str(" (truncated)")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEnumError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn match_token_first_wins() {
+        let table = [("a", 1), ("b", 2), ("A", 3)];
+        assert_eq!(match_token("A", &table), Some(1));
+        assert_eq!(match_token("B", &table), Some(2));
+        assert_eq!(match_token("", &table), None);
+    }
+
+    #[test]
+    fn match_token_folds_case() {
+        assert_eq!(match_token("İ", &[("i", ())]), Some(()));
+        assert_eq!(match_token("JA", &[("ja", ())]), Some(()));
+    }
+
+    #[test]
+    fn error_truncated() {
+        let err = ParseEnumError::new("Mode", &"x".repeat(100));
+        assert!(err.is_truncated());
+        assert_eq!(err.type_name(), "Mode");
+        assert!(err.to_string().ends_with("\" (truncated)"));
+    }
+
+    #[cfg(feature = "derive")]
+    mod derive {
+        use crate::LenientFromStr;
+
+        #[derive(Debug, PartialEq, LenientFromStr)]
+        enum Mode {
+            #[lenient(tokens("on", "yes", "y", "ja"))]
+            On,
+            #[lenient(tokens("off", "no"), tokens("n"))]
+            Off,
+            Auto,
+        }
+
+        #[test]
+        fn derive_from_str() {
+            assert_eq!("JA".parse(), Ok(Mode::On));
+            assert_eq!("n".parse(), Ok(Mode::Off));
+            assert_eq!("auto".parse(), Ok(Mode::Auto));
+            let err = "sometimes".parse::<Mode>().unwrap_err();
+            assert_eq!(err.to_string(), r#"invalid Mode value "sometimes""#);
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Environment variable flags.
+//!
+//! `EnvBool` follows the usual convention for flags passed in the environment: an unset
+//! variable is false, a variable that is set but empty is true, and anything else is parsed
+//! with the `LenientBool` rules. So `DEBUG= ./app` and `DEBUG=1 ./app` both turn `DEBUG` on,
+//! `DEBUG=no ./app` turns it off, and `DEBUG=maybe ./app` is an error.
+//!
+//! `get` and `get_or` read a variable with the plain `LenientBool` rules instead, where an
+//! empty value is an error like any other unrecognized value.
+
+use std::env;
+use std::ffi::OsStr;
+use std::ops::Deref;
+
+use super::{LenientBool, LenientBoolError};
+
+/// A flag read with environment variable conventions.
+///
+/// ```
+/// use lenient_bool::env::EnvBool;
+///
+/// assert_eq!(EnvBool::from_value(None), Ok(EnvBool(false)));
+/// assert_eq!(EnvBool::from_value(Some("")), Ok(EnvBool(true)));
+/// assert!(EnvBool::from_value(Some("off")).is_err());
+/// assert_eq!(EnvBool::from_value(Some("No")), Ok(EnvBool(false)));
+/// ```
+///
+/// With the `serde` feature, `EnvBool` can be used as a field in structs deserialized from
+/// the environment, for example with `envy`. Missing fields are false without needing
+/// `#[serde(default)]`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct EnvBool(pub bool);
+
+impl EnvBool {
+    /// Interprets the value of a variable, with `None` meaning that it is unset.
+    pub fn from_value(value: Option<&str>) -> Result<EnvBool, LenientBoolError> {
+        match value {
+            None => Ok(EnvBool(false)),
+            Some("") => Ok(EnvBool(true)),
+            Some(s) => s.parse::<LenientBool>().map(|b| EnvBool(b.0)),
+        }
+    }
+
+    /// Reads the environment variable `name`.
+    ///
+    /// Values that aren't valid Unicode are an error.
+    pub fn from_var<K: AsRef<OsStr>>(name: K) -> Result<EnvBool, LenientBoolError> {
+        match env::var_os(name) {
+            None => Ok(EnvBool(false)),
+            Some(value) if value.is_empty() => Ok(EnvBool(true)),
+            Some(value) => LenientBool::from_os_str(&value).map(|b| EnvBool(b.0)),
+        }
+    }
+}
+
+/// Reads the environment variable `name`, returning `None` if it is unset.
+///
+/// A set value is parsed with the `LenientBool` rules, so a value that isn't recognized,
+/// including an empty one or one that isn't valid Unicode, is an error rather than `None`.
+///
+/// ```
+/// use lenient_bool::env;
+///
+/// std::env::set_var("LENIENT_BOOL_DOC_GET", "yes");
+/// assert_eq!(env::get("LENIENT_BOOL_DOC_GET"), Ok(Some(true)));
+/// assert_eq!(env::get("LENIENT_BOOL_DOC_UNSET"), Ok(None));
+/// ```
+pub fn get<K: AsRef<OsStr>>(name: K) -> Result<Option<bool>, LenientBoolError> {
+    env::var_os(name)
+        .map(|value| LenientBool::from_os_str(&value).map(bool::from))
+        .transpose()
+}
+
+/// Reads the environment variable `name` like `get`, returning `default` if it is unset.
+///
+/// ```
+/// use lenient_bool::env;
+///
+/// assert_eq!(env::get_or("LENIENT_BOOL_DOC_UNSET", true), Ok(true));
+/// ```
+pub fn get_or<K: AsRef<OsStr>>(name: K, default: bool) -> Result<bool, LenientBoolError> {
+    get(name).map(|value| value.unwrap_or(default))
+}
+
+impl From<EnvBool> for bool {
+    fn from(b: EnvBool) -> bool {
+        b.0
+    }
+}
+
+impl From<bool> for EnvBool {
+    fn from(b: bool) -> EnvBool {
+        EnvBool(b)
+    }
+}
+
+impl Deref for EnvBool {
+    type Target = bool;
+    fn deref(&self) -> &bool {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+mod de {
+    use std::fmt;
+
+    use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+
+    use super::EnvBool;
+
+    struct EnvBoolVisitor;
+
+    impl<'de> Visitor<'de> for EnvBoolVisitor {
+        type Value = EnvBool;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an empty string or a boolean string such as true, false, yes, or no")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<EnvBool, E>
+        where
+            E: de::Error,
+        {
+            EnvBool::from_value(Some(v))
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+
+        fn visit_none<E>(self) -> Result<EnvBool, E> {
+            Ok(EnvBool(false))
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<EnvBool, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    /// Deserializes an `EnvBool` from an optional string. Missing fields are false.
+    ///
+    /// ```
+    /// use lenient_bool::env::EnvBool;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Flags {
+    ///     debug: EnvBool,
+    ///     trace: EnvBool,
+    ///     color: EnvBool,
+    /// }
+    ///
+    /// let vars = [
+    ///     ("DEBUG".to_owned(), "".to_owned()),
+    ///     ("COLOR".to_owned(), "no".to_owned()),
+    /// ];
+    /// let flags: Flags = envy::from_iter(vars).unwrap();
+    /// assert!(*flags.debug);
+    /// assert!(!*flags.trace);
+    /// assert!(!*flags.color);
+    /// ```
+    impl<'de> Deserialize<'de> for EnvBool {
+        fn deserialize<D>(deserializer: D) -> Result<EnvBool, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_option(EnvBoolVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unset_is_false() {
+        assert_eq!(EnvBool::from_value(None), Ok(EnvBool(false)));
+    }
+
+    #[test]
+    fn empty_is_true() {
+        assert_eq!(EnvBool::from_value(Some("")), Ok(EnvBool(true)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-yes-no"))]
+    fn value_is_parsed() {
+        assert_eq!(EnvBool::from_value(Some("0")), Ok(EnvBool(false)));
+        assert_eq!(EnvBool::from_value(Some("YES")), Ok(EnvBool(true)));
+    }
+
+    #[test]
+    fn bad_value_err() {
+        assert!(EnvBool::from_value(Some("abc")).is_err());
+    }
+
+    #[test]
+    fn from_unset_var() {
+        assert_eq!(
+            EnvBool::from_var("LENIENT_BOOL_TEST_UNSET_VARIABLE"),
+            Ok(EnvBool(false))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn get_set_var() {
+        env::set_var("LENIENT_BOOL_TEST_GET", "No");
+        assert_eq!(get("LENIENT_BOOL_TEST_GET"), Ok(Some(false)));
+        assert_eq!(get_or("LENIENT_BOOL_TEST_GET", true), Ok(false));
+    }
+
+    #[test]
+    fn get_unset_var() {
+        assert_eq!(get("LENIENT_BOOL_TEST_UNSET_VARIABLE"), Ok(None));
+        assert_eq!(get_or("LENIENT_BOOL_TEST_UNSET_VARIABLE", true), Ok(true));
+    }
+
+    #[test]
+    fn get_bad_value_err() {
+        env::set_var("LENIENT_BOOL_TEST_GET_BAD", "maybe");
+        assert_eq!(
+            get("LENIENT_BOOL_TEST_GET_BAD"),
+            Err(LenientBoolError::new("maybe"))
+        );
+        env::set_var("LENIENT_BOOL_TEST_GET_EMPTY", "");
+        assert!(get_or("LENIENT_BOOL_TEST_GET_EMPTY", false).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use ::serde::Deserialize;
+
+        use super::*;
+
+        #[derive(Debug, Deserialize)]
+        struct Flags {
+            debug: EnvBool,
+        }
+
+        fn from_env(vars: &[(&str, &str)]) -> Result<bool, envy::Error> {
+            let vars = vars.iter().map(|(k, v)| (k.to_string(), v.to_string()));
+            envy::from_iter::<_, Flags>(vars).map(|f| *f.debug)
+        }
+
+        #[test]
+        fn envy_missing() {
+            assert!(!from_env(&[]).unwrap());
+        }
+
+        #[test]
+        fn envy_empty() {
+            assert!(from_env(&[("DEBUG", "")]).unwrap());
+        }
+
+        #[test]
+        fn envy_value() {
+            assert!(!from_env(&[("DEBUG", "n")]).unwrap());
+        }
+
+        #[test]
+        fn envy_bad_value_err() {
+            assert!(from_env(&[("DEBUG", "abc")]).is_err());
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Using `LenientBool` with web framework query and form extractors.
+//!
+//! Query strings and urlencoded form bodies carry every value as a string, so a plain `bool`
+//! field only accepts the exact values `true` and `false`. Declaring the field as
+//! `LenientBool` instead accepts anything that `LenientBool` parses, such as `?verbose=yes` or
+//! `?verbose=1`. Use `Option<LenientBool>` for parameters that may be left out.
+//!
+//! axum's `Query` and `Form` and actix-web's `web::Query` and `web::Form` all deserialize
+//! through `serde_urlencoded`, so this is exactly what happens inside those extractors:
+//!
+//! ```
+//! use lenient_bool::LenientBool;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Params {
+//!     verbose: LenientBool,
+//!     dry_run: Option<LenientBool>,
+//! }
+//!
+//! let params: Params = serde_urlencoded::from_str("verbose=yes").unwrap();
+//! assert!(*params.verbose);
+//! assert_eq!(params.dry_run, None);
+//!
+//! let params: Params = serde_urlencoded::from_str("verbose=0&dry_run=T").unwrap();
+//! assert!(!*params.verbose);
+//! assert_eq!(params.dry_run, Some(LenientBool(true)));
+//! ```
+//!
+//! The same struct can then be used in a handler:
+//!
+//! ```ignore
+//! // axum
+//! async fn list(Query(params): Query<Params>) -> String {
+//!     format!("verbose: {}", *params.verbose)
+//! }
+//!
+//! // actix-web
+//! #[get("/list")]
+//! async fn list(params: web::Query<Params>) -> String {
+//!     format!("verbose: {}", *params.verbose)
+//! }
+//! ```
+//!
+//! Nested query strings parsed with `serde_qs` work the same way.
+//!
+//! For flags that may be given without a value, such as `?verbose`, use `LenientQueryBool`.
+
+use std::fmt;
+use std::ops::Deref;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+
+use super::LenientBool;
+
+/// A query-string flag: absent is false, present without a value (`?verbose` or `?verbose=`)
+/// is true, and any other value is parsed with the `LenientBool` rules. Missing fields are
+/// false without needing `#[serde(default)]`.
+///
+/// This works with `serde_urlencoded`, and so with axum's and actix-web's extractors.
+/// `serde_qs` treats a value-less parameter as missing, so there it is false.
+///
+/// ```
+/// use lenient_bool::extract::LenientQueryBool;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Params {
+///     verbose: LenientQueryBool,
+/// }
+///
+/// let verbose = |query| serde_urlencoded::from_str::<Params>(query).map(|p| *p.verbose);
+/// assert_eq!(verbose("verbose"), Ok(true));
+/// assert_eq!(verbose("verbose=no"), Ok(false));
+/// assert_eq!(verbose(""), Ok(false));
+/// assert!(verbose("verbose=maybe").is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct LenientQueryBool(pub bool);
+
+impl From<LenientQueryBool> for bool {
+    fn from(b: LenientQueryBool) -> bool {
+        b.0
+    }
+}
+
+impl From<bool> for LenientQueryBool {
+    fn from(b: bool) -> LenientQueryBool {
+        LenientQueryBool(b)
+    }
+}
+
+impl Deref for LenientQueryBool {
+    type Target = bool;
+    fn deref(&self) -> &bool {
+        &self.0
+    }
+}
+
+struct LenientQueryBoolVisitor;
+
+impl<'de> Visitor<'de> for LenientQueryBoolVisitor {
+    type Value = LenientQueryBool;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an empty value, or a boolean string such as true, yes, or 1")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<LenientQueryBool, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            return Ok(LenientQueryBool(true));
+        }
+        v.parse::<LenientBool>()
+            .map(|b| LenientQueryBool(b.0))
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<LenientQueryBool, E> {
+        Ok(LenientQueryBool(v))
+    }
+
+    fn visit_none<E>(self) -> Result<LenientQueryBool, E> {
+        Ok(LenientQueryBool(false))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<LenientQueryBool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LenientQueryBool {
+    fn deserialize<D>(deserializer: D) -> Result<LenientQueryBool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(LenientQueryBoolVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Params {
+        flag: LenientBool,
+        opt: Option<LenientBool>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Nested {
+        params: Params,
+    }
+
+    #[test]
+    fn urlencoded_yes() {
+        let p: Params = serde_urlencoded::from_str("flag=yes").unwrap();
+        assert_eq!(
+            p,
+            Params {
+                flag: LenientBool(true),
+                opt: None
+            }
+        );
+    }
+
+    #[test]
+    fn urlencoded_option() {
+        let p: Params = serde_urlencoded::from_str("flag=0&opt=Y").unwrap();
+        assert_eq!(
+            p,
+            Params {
+                flag: LenientBool(false),
+                opt: Some(LenientBool(true))
+            }
+        );
+    }
+
+    #[test]
+    fn urlencoded_bad_input_err() {
+        assert!(serde_urlencoded::from_str::<Params>("flag=abc").is_err());
+    }
+
+    #[test]
+    fn qs_nested() {
+        let p: Nested = serde_qs::from_str("params[flag]=F&params[opt]=1").unwrap();
+        assert_eq!(
+            p.params,
+            Params {
+                flag: LenientBool(false),
+                opt: Some(LenientBool(true))
+            }
+        );
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Flags {
+        enabled: LenientQueryBool,
+    }
+
+    #[test]
+    fn query_bool_values() {
+        for (query, value) in [
+            ("enabled=yes", true),
+            ("enabled=1", true),
+            ("enabled", true),
+            ("enabled=", true),
+            ("enabled=F", false),
+            ("", false),
+            ("other=1", false),
+        ] {
+            let flags: Flags = serde_urlencoded::from_str(query).unwrap();
+            assert_eq!(*flags.enabled, value, "{:?}", query);
+        }
+    }
+
+    #[test]
+    fn query_bool_bad_input_err() {
+        assert!(serde_urlencoded::from_str::<Flags>("enabled=abc").is_err());
+    }
+
+    #[test]
+    fn query_bool_qs() {
+        let flags: Flags = serde_qs::from_str("enabled=true").unwrap();
+        assert!(*flags.enabled);
+        // `serde_qs` treats empty values as missing.
+        let flags: Flags = serde_qs::from_str("enabled=").unwrap();
+        assert!(!*flags.enabled);
+    }
+
+    #[test]
+    fn qs_bad_input_err() {
+        assert!(serde_qs::from_str::<Params>("flag=abc").is_err());
+    }
+}
@@ -0,0 +1,200 @@
+//! A C interface to the parser.
+//!
+//! The functions here are declared in `include/lenient_bool.h`. Since Cargo can't choose a
+//! crate type by feature, build the library for C with
+//! `cargo rustc --release --features ffi --crate-type cdylib`, or `--crate-type staticlib`
+//! for static linking.
+//!
+//! The return codes are stable and match the `LENIENT_BOOL_*` constants in the header.
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::sync::OnceLock;
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+/// The value was parsed successfully.
+pub const LENIENT_BOOL_OK: c_int = 0;
+/// The value was not recognized as a boolean.
+pub const LENIENT_BOOL_INVALID: c_int = 1;
+/// A required pointer argument was null.
+pub const LENIENT_BOOL_NULL_POINTER: c_int = 2;
+
+/// Parses the NUL-terminated string `s`, storing the result in `out` on success.
+///
+/// Returns `LENIENT_BOOL_OK` on success, `LENIENT_BOOL_INVALID` if the string isn't
+/// recognized, or `LENIENT_BOOL_NULL_POINTER` if either pointer is null. `out` is only
+/// written on success.
+///
+/// # Safety
+///
+/// `s` must be null or point to a valid NUL-terminated string, and `out` must be null or
+/// valid for writing a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn lenient_bool_parse(s: *const c_char, out: *mut bool) -> c_int {
+    if s.is_null() || out.is_null() {
+        return LENIENT_BOOL_NULL_POINTER;
+    }
+
+    let parsed = CStr::from_ptr(s)
+        .to_str()
+        .ok()
+        .and_then(|s| s.parse::<LenientBool>().ok());
+    match parsed {
+        Some(b) => {
+            *out = b.0;
+            LENIENT_BOOL_OK
+        }
+        None => LENIENT_BOOL_INVALID,
+    }
+}
+
+/// Parses the `len` bytes at `s`, which don't need to be NUL-terminated, storing the result in
+/// `out` on success. This suits C++ `std::string_view` and other buffers that carry a length.
+///
+/// Returns the same codes as `lenient_bool_parse`.
+///
+/// # Safety
+///
+/// `s` must be null or valid for reading `len` bytes, and `out` must be null or valid for
+/// writing a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn lenient_bool_parse_len(
+    s: *const c_char,
+    len: usize,
+    out: *mut bool,
+) -> c_int {
+    if s.is_null() || out.is_null() {
+        return LENIENT_BOOL_NULL_POINTER;
+    }
+
+    let bytes = std::slice::from_raw_parts(s.cast::<u8>(), len);
+    match LenientBool::from_bytes(bytes) {
+        Ok(b) => {
+            *out = b.0;
+            LENIENT_BOOL_OK
+        }
+        Err(_) => LENIENT_BOOL_INVALID,
+    }
+}
+
+fn tokens() -> &'static [(CString, bool)] {
+    static TOKENS: OnceLock<Vec<(CString, bool)>> = OnceLock::new();
+    TOKENS.get_or_init(|| {
+        let true_tokens = TRUE_TOKENS.iter().map(|t| (*t, true));
+        let false_tokens = FALSE_TOKENS.iter().map(|t| (*t, false));
+        true_tokens
+            .chain(false_tokens)
+            .map(|(t, value)| (CString::new(t).unwrap(), value))
+            .collect()
+    })
+}
+
+/// Returns the number of accepted tokens.
+#[no_mangle]
+pub extern "C" fn lenient_bool_token_count() -> usize {
+    tokens().len()
+}
+
+/// Returns the accepted token at `index` as a static NUL-terminated string, or null if
+/// `index` is out of range. If `value` is not null, the boolean that the token parses to is
+/// stored there.
+///
+/// Tokens are matched case-insensitively, and are returned in lowercase.
+///
+/// # Safety
+///
+/// `value` must be null or valid for writing a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn lenient_bool_token(index: usize, value: *mut bool) -> *const c_char {
+    match tokens().get(index) {
+        Some((token, token_value)) => {
+            if !value.is_null() {
+                *value = *token_value;
+            }
+            token.as_ptr()
+        }
+        None => std::ptr::null(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ptr;
+
+    use super::*;
+
+    fn parse(s: &CStr) -> (c_int, bool) {
+        let mut out = false;
+        let code = unsafe { lenient_bool_parse(s.as_ptr(), &mut out) };
+        (code, out)
+    }
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(parse(c"yes"), (LENIENT_BOOL_OK, true));
+    }
+
+    #[test]
+    fn parse_0() {
+        assert_eq!(parse(c"0"), (LENIENT_BOOL_OK, false));
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        assert_eq!(parse(c"abc"), (LENIENT_BOOL_INVALID, false));
+    }
+
+    #[test]
+    fn parse_null_err() {
+        let mut out = false;
+        let code = unsafe { lenient_bool_parse(ptr::null(), &mut out) };
+        assert_eq!(code, LENIENT_BOOL_NULL_POINTER);
+        let code = unsafe { lenient_bool_parse(c"yes".as_ptr(), ptr::null_mut()) };
+        assert_eq!(code, LENIENT_BOOL_NULL_POINTER);
+    }
+
+    #[test]
+    fn parse_len() {
+        let parse_len = |bytes: &[u8]| {
+            let mut out = false;
+            let code =
+                unsafe { lenient_bool_parse_len(bytes.as_ptr().cast(), bytes.len(), &mut out) };
+            (code, out)
+        };
+        assert_eq!(parse_len(&b"yes, no"[..3]), (LENIENT_BOOL_OK, true));
+        assert_eq!(parse_len(b"off"), (LENIENT_BOOL_INVALID, false));
+        assert_eq!(parse_len(b"\xff"), (LENIENT_BOOL_INVALID, false));
+        let code = unsafe { lenient_bool_parse_len(ptr::null(), 0, &mut false) };
+        assert_eq!(code, LENIENT_BOOL_NULL_POINTER);
+    }
+
+    #[test]
+    fn header_declares_functions() {
+        let header = include_str!("../include/lenient_bool.h");
+        for declaration in [
+            "int lenient_bool_parse(const char *s, bool *out);",
+            "int lenient_bool_parse_len(const char *s, size_t len, bool *out);",
+            "size_t lenient_bool_token_count(void);",
+            "const char *lenient_bool_token(size_t index, bool *value);",
+        ] {
+            assert!(header.contains(declaration), "{}", declaration);
+        }
+    }
+
+    #[test]
+    fn token_list() {
+        let count = lenient_bool_token_count();
+        assert_eq!(count, TRUE_TOKENS.len() + FALSE_TOKENS.len());
+
+        let mut value = false;
+        let first = unsafe { CStr::from_ptr(lenient_bool_token(0, &mut value)) };
+        assert_eq!(first, c"true");
+        assert!(value);
+
+        let last = unsafe { CStr::from_ptr(lenient_bool_token(count - 1, &mut value)) };
+        assert_eq!(last.to_str(), Ok(FALSE_TOKENS[FALSE_TOKENS.len() - 1]));
+        assert!(!value);
+
+        assert!(unsafe { lenient_bool_token(count, ptr::null_mut()) }.is_null());
+    }
+}
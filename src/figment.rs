@@ -0,0 +1,222 @@
+//! Integration with `figment`.
+//!
+//! Values from environment variables and other string-based sources often hold booleans in
+//! spellings that figment doesn't recognize. `extract_bool` reads a single value with the
+//! `LenientBool` rules, and `NormalizeBools` wraps a provider so that chosen keys are
+//! converted to real booleans before the configuration is extracted.
+//!
+//! Both follow figment's profiles: `extract_bool` looks in the figment's selected profile,
+//! and `NormalizeBools` converts the keys in every profile that the provider supplies.
+//!
+//! ```
+//! use figment::{providers::Serialized, Figment};
+//! use lenient_bool::figment::NormalizeBools;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Settings {
+//!     debug: bool,
+//! }
+//!
+//! let provider = Serialized::default("debug", "Y");
+//! let figment = Figment::new().merge(NormalizeBools::new(provider, ["debug"]));
+//! let settings: Settings = figment.extract().unwrap();
+//! assert!(settings.debug);
+//! ```
+
+use ::figment::value::{Dict, Map, Num, Value};
+use ::figment::{Error, Figment, Metadata, Profile, Provider};
+
+use super::LenientBool;
+
+/// Converts a figment value to a `bool`.
+///
+/// Booleans convert directly, numbers must be `0` or `1`, and strings are parsed with the
+/// `LenientBool` rules. Any other value returns `None`.
+pub fn to_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(_, b) => Some(*b),
+        Value::Num(_, num) => num_to_bool(num),
+        Value::String(_, s) => s.parse::<LenientBool>().ok().map(bool::from),
+        _ => None,
+    }
+}
+
+fn num_to_bool(num: &Num) -> Option<bool> {
+    match num.to_u128_lossy() {
+        Some(0) => Some(false),
+        Some(1) => Some(true),
+        _ => None,
+    }
+}
+
+/// Finds the value at the dotted `key` in the figment's selected profile and converts it to
+/// a `bool` with `to_bool`.
+// `figment::Error` is large, but matching figment's own signatures is more useful here.
+#[allow(clippy::result_large_err)]
+pub fn extract_bool(figment: &Figment, key: &str) -> Result<bool, Error> {
+    let value = figment.find_value(key)?;
+    to_bool(&value).ok_or_else(|| {
+        let mut err = Error::from(format!("invalid boolean value for `{}`", key)).with_path(key);
+        err.metadata = figment.get_metadata(value.tag()).cloned();
+        err
+    })
+}
+
+/// A provider adapter that converts boolean-like values to booleans.
+///
+/// Each of the given dotted keys is converted with `to_bool` in every profile of the wrapped
+/// provider's data. Values that don't convert are left as they are, so extracting them still
+/// fails with figment's usual error. Other keys are untouched.
+#[derive(Debug, Clone)]
+pub struct NormalizeBools<P> {
+    provider: P,
+    keys: Vec<String>,
+}
+
+impl<P> NormalizeBools<P> {
+    /// Wraps `provider`, normalizing the values at `keys`.
+    pub fn new<I, S>(provider: P, keys: I) -> NormalizeBools<P>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        NormalizeBools {
+            provider,
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+fn normalize(dict: &mut Dict, key: &str) {
+    let (head, rest) = match key.split_once('.') {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (key, None),
+    };
+
+    match (dict.get_mut(head), rest) {
+        (Some(Value::Dict(_, inner)), Some(rest)) => normalize(inner, rest),
+        (Some(value), None) => {
+            if let Some(b) = to_bool(value) {
+                *value = Value::Bool(value.tag(), b);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl<P: Provider> Provider for NormalizeBools<P> {
+    fn metadata(&self) -> Metadata {
+        self.provider.metadata()
+    }
+
+    fn data(&self) -> Result<Map<Profile, Dict>, Error> {
+        let mut data = self.provider.data()?;
+        for dict in data.values_mut() {
+            for key in &self.keys {
+                normalize(dict, key);
+            }
+        }
+        Ok(data)
+    }
+
+    fn profile(&self) -> Option<Profile> {
+        self.provider.profile()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::figment::providers::Serialized;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Settings {
+        debug: bool,
+        server: Server,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Server {
+        tls: bool,
+        port: u16,
+    }
+
+    fn figment(debug: &str) -> Figment {
+        Figment::new()
+            .merge(Serialized::default("debug", debug))
+            .merge(Serialized::default("server.tls", "F"))
+            .merge(Serialized::default("server.port", 1))
+    }
+
+    #[test]
+    fn extract_yes() {
+        assert!(extract_bool(&figment("yes"), "debug").unwrap());
+    }
+
+    #[test]
+    fn extract_nested() {
+        assert!(!extract_bool(&figment("yes"), "server.tls").unwrap());
+    }
+
+    #[test]
+    fn extract_bad_input_err() {
+        let err = extract_bool(&figment("abc"), "debug").unwrap_err();
+        assert_eq!(err.path, vec!["debug".to_owned()]);
+    }
+
+    #[test]
+    fn extract_missing_err() {
+        assert!(extract_bool(&figment("yes"), "other")
+            .unwrap_err()
+            .missing());
+    }
+
+    #[test]
+    fn extract_selected_profile() {
+        let figment = figment("yes")
+            .merge(Serialized::default("debug", "N").profile("prod"))
+            .select("prod");
+        assert!(!extract_bool(&figment, "debug").unwrap());
+    }
+
+    #[test]
+    fn normalize_keys() {
+        let figment = Figment::new().merge(NormalizeBools::new(
+            Serialized::defaults(serde_json::json!({
+                "debug": "T",
+                "server": { "tls": "0", "port": 1 },
+            })),
+            ["debug", "server.tls"],
+        ));
+        let settings: Settings = figment.extract().unwrap();
+        assert_eq!(
+            settings,
+            Settings {
+                debug: true,
+                server: Server {
+                    tls: false,
+                    port: 1
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_all_profiles() {
+        let provider = Serialized::default("debug", "n").profile("prod");
+        let figment = Figment::new()
+            .merge(NormalizeBools::new(provider, ["debug"]))
+            .select("prod");
+        assert_eq!(figment.find_value("debug").unwrap().to_bool(), Some(false));
+    }
+
+    #[test]
+    fn normalize_leaves_bad_input() {
+        let provider = Serialized::default("debug", "abc");
+        let figment = Figment::new().merge(NormalizeBools::new(provider, ["debug"]));
+        assert_eq!(figment.find_value("debug").unwrap().as_str(), Some("abc"));
+    }
+}
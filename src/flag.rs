@@ -0,0 +1,99 @@
+use core::ops::Deref;
+use core::str::FromStr;
+
+use super::{LenientBool, LenientBoolError};
+
+/// A command-line flag that may be given a value, as in `--color` or `--color=no`.
+///
+/// An absent flag is `false`, which is also the `Default`. A flag given without a value, or
+/// with an empty one, is `true`, and any other value is parsed with the `LenientBool`
+/// rules. `LenientFlag::try_from` takes the flag's value, if it was given, as an
+/// `Option<&str>`. For an absent flag that is `None`, and for a flag without a value it is
+/// `Some("")`.
+///
+/// ```
+/// use lenient_bool::LenientFlag;
+///
+/// assert_eq!(LenientFlag::try_from(None), Ok(LenientFlag(false)));
+/// assert_eq!(LenientFlag::try_from(Some("")), Ok(LenientFlag(true)));
+/// assert_eq!(LenientFlag::try_from(Some("no")), Ok(LenientFlag(false)));
+/// assert!(LenientFlag::try_from(Some("maybe")).is_err());
+/// ```
+///
+/// With the `clap` feature, `lenient_bool::clap::lenient_flag` sets up an `Arg` this way.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct LenientFlag(pub bool);
+
+impl<'a> TryFrom<Option<&'a str>> for LenientFlag {
+    type Error = LenientBoolError;
+
+    fn try_from(value: Option<&'a str>) -> Result<LenientFlag, LenientBoolError> {
+        match value {
+            None => Ok(LenientFlag(false)),
+            Some(s) => s.parse(),
+        }
+    }
+}
+
+/// Parses the value given to a flag, with the empty string as `true`.
+impl FromStr for LenientFlag {
+    type Err = LenientBoolError;
+
+    fn from_str(s: &str) -> Result<LenientFlag, LenientBoolError> {
+        if s.is_empty() {
+            return Ok(LenientFlag(true));
+        }
+        s.parse::<LenientBool>().map(|b| LenientFlag(b.0))
+    }
+}
+
+impl From<LenientFlag> for bool {
+    fn from(f: LenientFlag) -> bool {
+        f.0
+    }
+}
+
+impl From<bool> for LenientFlag {
+    fn from(b: bool) -> LenientFlag {
+        LenientFlag(b)
+    }
+}
+
+impl Deref for LenientFlag {
+    type Target = bool;
+    fn deref(&self) -> &bool {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn absent() {
+        assert_eq!(LenientFlag::default(), LenientFlag(false));
+        assert_eq!(LenientFlag::try_from(None), Ok(LenientFlag(false)));
+    }
+
+    #[test]
+    fn bare() {
+        assert_eq!(LenientFlag::try_from(Some("")), Ok(LenientFlag(true)));
+        assert_eq!("".parse(), Ok(LenientFlag(true)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-single-letter"))]
+    fn values() {
+        assert_eq!("Y".parse(), Ok(LenientFlag(true)));
+        assert_eq!("0".parse(), Ok(LenientFlag(false)));
+    }
+
+    #[test]
+    fn bad_input_err() {
+        assert_eq!(
+            LenientFlag::try_from(Some("abc")),
+            Err(LenientBoolError::new("abc"))
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! HTML form checkboxes.
+//!
+//! A checked checkbox is submitted with its `value` attribute, which is `on` unless the page
+//! sets another, and an unchecked one isn't submitted at all. `FormBool` follows those
+//! semantics: a missing or empty value is false, `on` and `checked` are true, `off` is false,
+//! and anything else is parsed with the `LenientBool` rules, so `value="true"` and
+//! `value="1"` also work.
+
+use core::ops::Deref;
+use core::str::FromStr;
+
+use super::{eq_ignore_case, LenientBool, LenientBoolError};
+
+/// A checkbox value from a submitted HTML form.
+///
+/// ```
+/// use lenient_bool::form::FormBool;
+///
+/// assert_eq!(FormBool::from_value(None), Ok(FormBool(false)));
+/// assert_eq!(FormBool::from_value(Some("on")), Ok(FormBool(true)));
+/// assert_eq!(FormBool::from_value(Some("")), Ok(FormBool(false)));
+/// assert_eq!("checked".parse(), Ok(FormBool(true)));
+/// assert!("maybe".parse::<FormBool>().is_err());
+/// ```
+///
+/// With the `serde` feature, `FormBool` can be used as a field in structs deserialized from
+/// form data, for example with `serde_urlencoded`. Missing fields are false without needing
+/// `#[serde(default)]`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct FormBool(pub bool);
+
+impl FormBool {
+    /// Interprets a submitted value, with `None` meaning that the field was absent.
+    pub fn from_value(value: Option<&str>) -> Result<FormBool, LenientBoolError> {
+        value.map_or(Ok(FormBool(false)), str::parse)
+    }
+}
+
+impl FromStr for FormBool {
+    type Err = LenientBoolError;
+    fn from_str(s: &str) -> Result<FormBool, LenientBoolError> {
+        if s.is_empty() || eq_ignore_case(s, "off") {
+            Ok(FormBool(false))
+        } else if eq_ignore_case(s, "on") || eq_ignore_case(s, "checked") {
+            Ok(FormBool(true))
+        } else {
+            s.parse::<LenientBool>().map(|b| FormBool(b.0))
+        }
+    }
+}
+
+impl From<FormBool> for bool {
+    fn from(b: FormBool) -> bool {
+        b.0
+    }
+}
+
+impl From<bool> for FormBool {
+    fn from(b: bool) -> FormBool {
+        FormBool(b)
+    }
+}
+
+impl Deref for FormBool {
+    type Target = bool;
+    fn deref(&self) -> &bool {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+mod de {
+    use std::fmt;
+
+    use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+
+    use super::FormBool;
+
+    struct FormBoolVisitor;
+
+    impl<'de> Visitor<'de> for FormBoolVisitor {
+        type Value = FormBool;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a checkbox value such as on, or a boolean string such as true or 1")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<FormBool, E>
+        where
+            E: de::Error,
+        {
+            v.parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<FormBool, E> {
+            Ok(FormBool(v))
+        }
+
+        fn visit_none<E>(self) -> Result<FormBool, E> {
+            Ok(FormBool(false))
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<FormBool, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    /// Deserializes a `FormBool` from an optional string. Missing fields are false.
+    ///
+    /// ```
+    /// use lenient_bool::form::FormBool;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Signup {
+    ///     newsletter: FormBool,
+    ///     terms: FormBool,
+    /// }
+    ///
+    /// let form: Signup = serde_urlencoded::from_str("terms=on").unwrap();
+    /// assert!(!*form.newsletter);
+    /// assert!(*form.terms);
+    /// ```
+    impl<'de> Deserialize<'de> for FormBool {
+        fn deserialize<D>(deserializer: D) -> Result<FormBool, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_option(FormBoolVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn absent_is_false() {
+        assert_eq!(FormBool::from_value(None), Ok(FormBool(false)));
+    }
+
+    #[test]
+    fn checkbox_values() {
+        for (s, value) in [
+            ("on", true),
+            ("ON", true),
+            ("checked", true),
+            ("", false),
+            ("off", false),
+        ] {
+            assert_eq!(s.parse(), Ok(FormBool(value)), "{}", s);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-yes-no"))]
+    fn custom_values() {
+        assert_eq!(FormBool::from_value(Some("true")), Ok(FormBool(true)));
+        assert_eq!(FormBool::from_value(Some("1")), Ok(FormBool(true)));
+        assert_eq!(FormBool::from_value(Some("no")), Ok(FormBool(false)));
+    }
+
+    #[test]
+    fn bad_value_err() {
+        assert_eq!(
+            FormBool::from_value(Some("abc")),
+            Err(LenientBoolError::new("abc"))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use ::serde::Deserialize;
+
+        use super::*;
+
+        #[derive(Debug, Deserialize)]
+        struct Form {
+            agree: FormBool,
+        }
+
+        fn from_form(s: &str) -> Result<bool, serde_urlencoded::de::Error> {
+            serde_urlencoded::from_str::<Form>(s).map(|f| *f.agree)
+        }
+
+        #[test]
+        fn urlencoded_missing() {
+            assert!(!from_form("").unwrap());
+        }
+
+        #[test]
+        fn urlencoded_on() {
+            assert!(from_form("agree=on").unwrap());
+        }
+
+        #[test]
+        fn urlencoded_empty() {
+            assert!(!from_form("agree=").unwrap());
+        }
+
+        #[test]
+        fn urlencoded_bad_value_err() {
+            assert!(from_form("agree=abc").is_err());
+        }
+
+        #[test]
+        fn json_bool() {
+            let form: Form = serde_json::from_str(r#"{"agree": true}"#).unwrap();
+            assert!(*form.agree);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Invariants that should hold for every input, shared by the fuzz targets in `fuzz/` and
+//! the tests. Each function panics if an invariant is violated.
+//!
+//! This module is not part of the stable API.
+
+use super::{LenientBool, LenientBoolParser, Token};
+
+fn canonical(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+/// Checks the invariants of parsing `s` as a string.
+pub fn check_str(s: &str) {
+    let parsed = s.parse::<LenientBool>().map(bool::from);
+
+    // The default parser and `FromStr` agree.
+    assert_eq!(LenientBoolParser::new().parse(s), parsed, "{:?}", s);
+
+    // Parsing the bytes directly agrees with parsing the string.
+    assert_eq!(
+        LenientBool::from_bytes(s.as_bytes()).map(bool::from),
+        parsed,
+        "{:?}",
+        s
+    );
+
+    // Errors report the input, or as much of it as fits.
+    if let Err(err) = parsed {
+        assert!(s.starts_with(err.input()), "{:?}", s);
+        assert_eq!(err.is_truncated(), err.input().len() < s.len(), "{:?}", s);
+    }
+
+    // The `const` parser agrees for ASCII input.
+    if s.is_ascii() {
+        assert_eq!(
+            LenientBool::const_parse(s).map(bool::from),
+            parsed.ok(),
+            "{:?}",
+            s
+        );
+    }
+
+    // Matching is case-insensitive.
+    assert_eq!(
+        s.to_ascii_uppercase()
+            .parse::<LenientBool>()
+            .map(bool::from)
+            .ok(),
+        parsed.ok(),
+        "{:?}",
+        s
+    );
+    assert_eq!(
+        s.to_ascii_lowercase()
+            .parse::<LenientBool>()
+            .map(bool::from)
+            .ok(),
+        parsed.ok(),
+        "{:?}",
+        s
+    );
+
+    // Canonicalizing a value doesn't change what it parses to.
+    if let Ok(value) = parsed {
+        assert_eq!(
+            canonical(value).parse::<LenientBool>(),
+            Ok(LenientBool(value)),
+            "{:?}",
+            s
+        );
+        #[cfg(not(feature = "nfkc"))]
+        assert!(
+            super::TRUE_TOKENS
+                .iter()
+                .chain(super::FALSE_TOKENS)
+                .any(|t| super::eq_ignore_case(s, t)),
+            "{:?}",
+            s
+        );
+    }
+
+    // A list of one item parses the same as the item, ignoring surrounding whitespace.
+    let list = LenientBool::parse_list(s, ',');
+    if !s.contains(',') && !s.trim().is_empty() {
+        let item = s.trim().parse::<LenientBool>().map(bool::from);
+        assert_eq!(list.ok(), item.ok().map(|v| vec![v]), "{:?}", s);
+    }
+
+    // The heuristic never fails, and agrees with the real parser's `true` values, other than
+    // localized ones.
+    let heuristic = LenientBool::parse_heuristic(s);
+    if parsed == Ok(true) && Token::ALL.iter().any(|t| s.eq_ignore_ascii_case(t.text())) {
+        assert!(heuristic, "{:?}", s);
+    }
+
+    // The prefix matcher only handles ASCII.
+    if let (Ok(value), true) = (parsed, s.is_ascii()) {
+        assert_eq!(
+            LenientBool::parse_prefix(s),
+            Some((LenientBool(value), "")),
+            "{:?}",
+            s
+        );
+    }
+}
+
+/// Checks the invariants of parsing raw bytes, decoded as UTF-8 and as UTF-16.
+pub fn check_bytes(bytes: &[u8]) {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => check_str(s),
+        // Every token is ASCII, so invalid UTF-8 never parses.
+        Err(_) => assert!(LenientBool::from_bytes(bytes).is_err(), "{:?}", bytes),
+    }
+
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    if let Ok(s) = String::from_utf16(&units) {
+        check_str(&s);
+    }
+
+    #[cfg(feature = "ffi")]
+    check_ffi(bytes);
+}
+
+/// Checks that the C interface agrees with `FromStr`.
+#[cfg(feature = "ffi")]
+fn check_ffi(bytes: &[u8]) {
+    use super::ffi::{lenient_bool_parse, LENIENT_BOOL_INVALID, LENIENT_BOOL_OK};
+
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    let c_string = std::ffi::CString::new(&bytes[..end]).unwrap();
+    let mut out = false;
+    let code = unsafe { lenient_bool_parse(c_string.as_ptr(), &mut out) };
+
+    let expected = std::str::from_utf8(&bytes[..end])
+        .ok()
+        .and_then(|s| s.parse::<LenientBool>().ok());
+    match expected {
+        Some(b) => assert_eq!((code, out), (LENIENT_BOOL_OK, b.0)),
+        None => assert_eq!(code, LENIENT_BOOL_INVALID),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tokens_hold() {
+        for token in Token::ALL.iter().filter(|t| t.is_enabled()) {
+            check_str(token.text());
+            check_bytes(token.text().as_bytes());
+        }
+    }
+
+    #[test]
+    fn edge_cases_hold() {
+        for s in [
+            "",
+            " ",
+            "yes,",
+            " no ",
+            "tRuE",
+            "yesterday",
+            "ǅ",
+            "\u{130}",
+            "1\0",
+        ] {
+            check_str(s);
+            check_bytes(s.as_bytes());
+        }
+    }
+
+    #[test]
+    fn utf16_holds() {
+        check_bytes(&[b'y', 0, b'E', 0, b'S', 0]);
+    }
+}
@@ -0,0 +1,282 @@
+use core::iter::FusedIterator;
+
+use super::{LenientBool, LenientBoolError};
+
+/// An iterator that parses each string from another iterator as a `LenientBool`.
+///
+/// This is created by `LenientBoolIterator::parse_lenient_bools`.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParseLenientBools<I> {
+    iter: I,
+}
+
+impl<I> Iterator for ParseLenientBools<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<bool, LenientBoolError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|s| s.as_ref().parse::<LenientBool>().map(bool::from))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for ParseLenientBools<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<str>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|s| s.as_ref().parse::<LenientBool>().map(bool::from))
+    }
+}
+
+impl<I> ExactSizeIterator for ParseLenientBools<I>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+impl<I> FusedIterator for ParseLenientBools<I>
+where
+    I: FusedIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Adapters for parsing every item of an iterator of strings, such as a CSV column.
+///
+/// ```
+/// use lenient_bool::LenientBoolIterator;
+///
+/// let column = ["yes", "N", "1"];
+/// assert_eq!(column.iter().collect_bools(), Ok(vec![true, false, true]));
+///
+/// let parsed: Vec<_> = ["t", "maybe"].iter().parse_lenient_bools().collect();
+/// assert_eq!(parsed[0], Ok(true));
+/// assert!(parsed[1].is_err());
+/// ```
+pub trait LenientBoolIterator: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Parses each item, yielding a `Result` per item.
+    fn parse_lenient_bools(self) -> ParseLenientBools<Self> {
+        ParseLenientBools { iter: self }
+    }
+
+    /// Parses every item, stopping at the first one that isn't a recognized boolean.
+    #[cfg(feature = "std")]
+    fn collect_bools(self) -> Result<Vec<bool>, LenientBoolError> {
+        self.parse_lenient_bools().collect()
+    }
+}
+
+impl<I> LenientBoolIterator for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// What `all_truthy`, `any_truthy`, and `count_truthy` do with an item that isn't recognized
+/// as a boolean.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Unparseable {
+    /// Stop and return an error.
+    #[default]
+    Error,
+    /// Leave the item out, as if it wasn't there.
+    Skip,
+    /// Treat the item as `false`.
+    False,
+}
+
+/// Parses each item, applying `policy` to the ones that aren't recognized. Skipped items
+/// come out as `None`.
+fn parse_with<I>(
+    values: I,
+    policy: Unparseable,
+) -> impl Iterator<Item = Result<Option<bool>, LenientBoolError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    values
+        .into_iter()
+        .parse_lenient_bools()
+        .map(move |result| match (result, policy) {
+            (Ok(b), _) => Ok(Some(b)),
+            (Err(err), Unparseable::Error) => Err(err),
+            (Err(_), Unparseable::Skip) => Ok(None),
+            (Err(_), Unparseable::False) => Ok(Some(false)),
+        })
+}
+
+/// Whether every item is truthy. Like `Iterator::all`, this is `true` for an empty iterator,
+/// including one where every item was skipped.
+///
+/// With `Unparseable::Error`, items are parsed until the first `false` one, so an
+/// unrecognized item after it isn't reported.
+///
+/// ```
+/// use lenient_bool::{all_truthy, Unparseable};
+///
+/// assert_eq!(all_truthy(["yes", "Y", "1"], Unparseable::Error), Ok(true));
+/// assert_eq!(all_truthy(["yes", "n/a"], Unparseable::Skip), Ok(true));
+/// assert_eq!(all_truthy(["yes", "n/a"], Unparseable::False), Ok(false));
+/// assert!(all_truthy(["yes", "n/a"], Unparseable::Error).is_err());
+/// ```
+pub fn all_truthy<I>(values: I, policy: Unparseable) -> Result<bool, LenientBoolError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    for b in parse_with(values, policy) {
+        if b? == Some(false) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Whether any item is truthy. Like `Iterator::any`, this is `false` for an empty iterator.
+///
+/// With `Unparseable::Error`, items are parsed until the first `true` one, so an
+/// unrecognized item after it isn't reported.
+///
+/// ```
+/// use lenient_bool::{any_truthy, Unparseable};
+///
+/// assert_eq!(any_truthy(["no", "N", "yes"], Unparseable::Error), Ok(true));
+/// assert_eq!(any_truthy(["no", "n/a"], Unparseable::Skip), Ok(false));
+/// assert!(any_truthy(["no", "n/a"], Unparseable::Error).is_err());
+/// ```
+pub fn any_truthy<I>(values: I, policy: Unparseable) -> Result<bool, LenientBoolError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    for b in parse_with(values, policy) {
+        if b? == Some(true) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Counts the truthy items.
+///
+/// ```
+/// use lenient_bool::{count_truthy, Unparseable};
+///
+/// assert_eq!(count_truthy(["yes", "no", "T", "?"], Unparseable::Skip), Ok(2));
+/// assert!(count_truthy(["yes", "no", "T", "?"], Unparseable::Error).is_err());
+/// ```
+pub fn count_truthy<I>(values: I, policy: Unparseable) -> Result<usize, LenientBoolError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut count = 0;
+    for b in parse_with(values, policy) {
+        if b? == Some(true) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_each() {
+        let parsed: Vec<_> = ["True", "abc", "false"]
+            .into_iter()
+            .parse_lenient_bools()
+            .collect();
+        assert_eq!(
+            parsed,
+            [Ok(true), Err(LenientBoolError::new("abc")), Ok(false)]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "tokens-numeric"))]
+    fn owned_strings() {
+        let column = vec![String::from("0"), String::from("TRUE")];
+        assert_eq!(column.into_iter().collect_bools(), Ok(vec![false, true]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "tokens-numeric"))]
+    fn collect_bools_first_err() {
+        assert_eq!(
+            ["1", "maybe", "nope"].iter().collect_bools(),
+            Err(LenientBoolError::new("maybe"))
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-yes-no"))]
+    fn all_truthy_policies() {
+        let column = ["1", "?", "yes"];
+        assert_eq!(
+            all_truthy(column, Unparseable::Error),
+            Err(LenientBoolError::new("?"))
+        );
+        assert_eq!(all_truthy(column, Unparseable::Skip), Ok(true));
+        assert_eq!(all_truthy(column, Unparseable::False), Ok(false));
+        assert_eq!(all_truthy(["?"], Unparseable::Skip), Ok(true));
+        assert_eq!(all_truthy(["no", "?"], Unparseable::Error), Ok(false));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn any_truthy_policies() {
+        let column = ["0", "?", "no"];
+        assert!(any_truthy(column, Unparseable::Error).is_err());
+        assert_eq!(any_truthy(column, Unparseable::Skip), Ok(false));
+        assert_eq!(any_truthy(column, Unparseable::False), Ok(false));
+        assert_eq!(
+            any_truthy(Vec::<String>::new(), Unparseable::Error),
+            Ok(false)
+        );
+        assert_eq!(any_truthy(["Y", "?"], Unparseable::Error), Ok(true));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "tokens-numeric",
+        feature = "tokens-single-letter",
+        feature = "tokens-yes-no"
+    ))]
+    fn count_truthy_policies() {
+        let column = vec![String::from("t"), String::from("x"), String::from("ON")];
+        assert!(count_truthy(&column, Unparseable::Error).is_err());
+        assert_eq!(count_truthy(&column, Unparseable::Skip), Ok(1));
+        assert_eq!(count_truthy(&column, Unparseable::False), Ok(1));
+        assert_eq!(count_truthy(["yes", "1", "n"], Unparseable::Error), Ok(2));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn reversed_and_len() {
+        let mut parsed = ["yes", "no"].iter().parse_lenient_bools();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed.next_back(), Some(Ok(false)));
+    }
+}
@@ -0,0 +1,93 @@
+//! Integration with `serde_json`.
+
+use ::serde_json::Value;
+
+use super::{LenientBool, LenientBoolError, Truthy};
+
+impl LenientBool {
+    /// Coerces a dynamically typed JSON value to a `LenientBool`.
+    ///
+    /// Booleans convert directly. Numbers are `false` when zero and `true` otherwise.
+    /// Strings are parsed with the `LenientBool` rules. Null, arrays, and objects are an
+    /// error.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(LenientBool::try_from_value(&json!(true)), Ok(LenientBool(true)));
+    /// assert_eq!(LenientBool::try_from_value(&json!(0)), Ok(LenientBool(false)));
+    /// assert_eq!(LenientBool::try_from_value(&json!("Yes")), Ok(LenientBool(true)));
+    /// assert!(LenientBool::try_from_value(&json!(null)).is_err());
+    /// ```
+    pub fn try_from_value(value: &Value) -> Result<LenientBool, LenientBoolError> {
+        match value {
+            Value::Bool(b) => Ok(LenientBool(*b)),
+            Value::Number(n) => Ok(LenientBool(n.as_f64() != Some(0.0))),
+            Value::String(s) => s.parse(),
+            _ => Err(LenientBoolError::new(&value.to_string())),
+        }
+    }
+}
+
+/// Converts a JSON value with `LenientBool::try_from_value`.
+impl<'a> TryFrom<&'a Value> for LenientBool {
+    type Error = LenientBoolError;
+
+    fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+        LenientBool::try_from_value(value)
+    }
+}
+
+/// Coerces a JSON value with `LenientBool::try_from_value`.
+impl Truthy for Value {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        LenientBool::try_from_value(self).map(bool::from)
+    }
+}
+
+impl From<LenientBool> for Value {
+    fn from(b: LenientBool) -> Value {
+        Value::Bool(b.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn from_numbers() {
+        assert_eq!(LenientBool::try_from(&json!(2)), Ok(LenientBool(true)));
+        assert_eq!(LenientBool::try_from(&json!(-1)), Ok(LenientBool(true)));
+        assert_eq!(LenientBool::try_from(&json!(0.0)), Ok(LenientBool(false)));
+        assert_eq!(LenientBool::try_from(&json!(0.5)), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn from_string_bad_input_err() {
+        assert_eq!(
+            LenientBool::try_from(&json!("abc")),
+            Err(LenientBoolError::new("abc"))
+        );
+    }
+
+    #[test]
+    fn from_array_err() {
+        assert!(LenientBool::try_from(&json!([true])).is_err());
+    }
+
+    #[test]
+    fn truthy() {
+        assert!(crate::truthy(json!("on")).is_err());
+        assert_eq!(crate::truthy(json!(1)), Ok(true));
+        assert_eq!(crate::truthy(Some(&json!("N"))), Ok(false));
+    }
+
+    #[test]
+    fn into_value() {
+        assert_eq!(Value::from(LenientBool(false)), json!(false));
+    }
+}
@@ -1,10 +1,95 @@
 //! This module provides a single type, `LenientBool`, which implements `FromStr` to convert
 //! a string into a boolean. It is more accepting of various boolean representations than
 //! the standard bool function, performing case-insensitive matches
-//! against `true`, `false`, `t`, `f`, `yes`, `no`, `y`, `n`, `0`, and `1`.
+//! against `true`, `false`, `t`, `f`, `yes`, `no`, `y`, `n`, `0`, and `1`. The same list is
+//! available programmatically as `Token::ALL`.
+//!
+//! The single-letter, yes/no, and numeric spellings can each be turned off by disabling the
+//! default `tokens-single-letter`, `tokens-yes-no`, and `tokens-numeric` features.
 //!
 //! # Errors
-//! Any string not matching the above list will return a `LenientBoolError`.
+//! Any string not matching the above list will return a `LenientBoolError`, which reports the
+//! offending input and implements `std::error::Error`, so it works with `?` in `anyhow` and
+//! `thiserror` error chains.
+//!
+//! # Profiles
+//!
+//! `LenientBoolParser` provides profiles that match how other languages parse booleans, such
+//! as `LenientBoolParser::go()` for Go's `strconv.ParseBool`. Extra spellings, such as the
+//! localized `TRUE` and `FALSE` literals from `TokenPack::excel`, can be added with
+//! `LenientBoolParser::with_pack`.
+//!
+//! # `no_std`
+//!
+//! The crate supports `no_std` when the default `std` feature is disabled. `LenientBool`,
+//! `LenientOptionBool`, `TriState`, `Token`, `TokenPack`, and `LenientBool::from_str_with`
+//! only need `core`. The configurable parser, list parsing, environment variables, and all
+//! of the integrations below need `std`, and enabling any integration enables `std` too.
+//!
+//! # Features
+//!
+//! * `arbitrary` - an `arbitrary::Arbitrary` implementation, and generators for valid and
+//!   invalid token strings, for fuzzing.
+//! * `argh` - the `lenient_bool::argh` module, describing how to use `LenientBool` as an
+//!   `argh` option type.
+//! * `arrow` - conversion of Arrow string arrays to boolean arrays.
+//! * `async-graphql` - a `LenientBool` GraphQL scalar for `async-graphql`.
+//! * `bitvec` - parsing a column of values into a `BitVec`, one bit per value.
+//! * `bpaf` - parsers for declaring lenient boolean options with `bpaf`.
+//! * `bson` - conversions between `LenientBool` and `bson::Bson` values.
+//! * `calamine` - conversion of spreadsheet cells read with `calamine` to booleans.
+//! * `chumsky` - `chumsky` parsers for boolean tokens, with labelled errors and recovery.
+//! * `clap` - a `TypedValueParser` for use with `clap` argument definitions.
+//! * `cli` - a `lenient-bool` command for shell scripts, which sets its exit status from a
+//!   value.
+//! * `config` - a helper for reading lenient booleans from a `config::Config`.
+//! * `csv` - helpers for reading boolean columns with the `csv` crate. Enables `serde`.
+//! * `derive` - `#[derive(LenientFromStr)]`, for enums parsed from lenient spellings.
+//! * `diesel` - Diesel `FromSql` and `ToSql` implementations for `Bool` and `Text` columns.
+//! * `ffi` - a C interface, declared in `include/lenient_bool.h`.
+//! * `figment` - a helper for extracting lenient booleans from a `figment::Figment`, and a
+//!   provider adapter that normalizes boolean values.
+//! * `fullwidth` - accept full-width forms of the tokens, such as `ｔｒｕｅ` and `１`, as
+//!   typed with Chinese and Japanese input methods.
+//! * `json` - `LenientBool::try_from_value` for coercing `serde_json::Value`s.
+//! * `locale-de`, `locale-es`, `locale-fr`, `locale-ja` - accept the German, Spanish, French,
+//!   or Japanese words for yes and no by default, as listed by `TokenPack::yes_no`.
+//! * `minijinja` - a `lenient_bool` template filter for `minijinja`.
+//! * `mlua` - `mlua::FromLua` and `IntoLua` implementations for Lua booleans, numbers, and
+//!   strings. A Lua version must be selected with one of `mlua`'s own features.
+//! * `napi` - Node.js bindings built with napi-rs.
+//! * `nfkc` - normalize non-ASCII input to Unicode NFKC before matching it against the
+//!   tokens, including those of a case-insensitive `LenientBoolParser`, so that
+//!   compatibility forms such as `ｙｅｓ` and `①` parse.
+//! * `nom` - a `nom` combinator that parses a boolean token.
+//! * `postgres` - `postgres_types::FromSql` and `ToSql` implementations for BOOL, integer,
+//!   and text columns, for use with `postgres` and `tokio-postgres`.
+//! * `proptest` - a `proptest` `Arbitrary` implementation and a strategy for every accepted
+//!   spelling, for property tests.
+//! * `python` - a Python extension module built with PyO3.
+//! * `quick-xml` - helpers for parsing XML attribute values and text read with `quick-xml`.
+//! * `quickcheck` - a `quickcheck::Arbitrary` implementation and a generator for every accepted
+//!   spelling, for property tests.
+//! * `rayon` - `parse_all_par`, which parses large slices in parallel.
+//! * `redis` - `FromRedisValue` and `ToRedisArgs` implementations for integer and string replies.
+//! * `rhai` - registration of `LenientBool` and a `to_bool` function with a Rhai engine.
+//! * `rocket` - `FromFormField` and `FromParam` implementations for Rocket handlers.
+//! * `rusqlite` - `FromSql` and `ToSql` implementations that read INTEGER and TEXT columns,
+//!   and a wrapper for writing a chosen text form.
+//! * `schemars` - a `JsonSchema` implementation describing the accepted values.
+//! * `sea-orm` - SeaORM value conversions, so entities can declare `LenientBool` columns.
+//! * `serde` - a `Deserialize` implementation that parses strings with the rules above and
+//!   also accepts native booleans and 0 and 1, a `Serialize` implementation with a choice of
+//!   output forms, and the `DefaultTrue` and `DefaultFalse` types that fall back to a
+//!   default instead of failing. See the `extract` module for using it with web framework
+//!   query extractors.
+//! * `std` - the parts of the crate that need the standard library. On by default.
+//! * `sqlx` - sqlx `Type`, `Decode`, and `Encode` implementations for any backend.
+//! * `tera` - a `lenient_bool` template filter for Tera.
+//! * `uniffi` - UniFFI bindings for Kotlin and Swift.
+//! * `validator` - a custom validation function for the `validator` crate.
+//! * `wasm` - JavaScript bindings built with `wasm-bindgen`.
+//! * `winnow` - a `winnow` parser that parses a boolean token.
 //!
 //! # Examples
 //!
@@ -18,234 +103,2533 @@
 //! }
 //! ```
 
-use std::ops::{Deref, DerefMut};
-use std::ascii::AsciiExt;
-use std::str::FromStr;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
-pub struct LenientBool(pub bool);
+// Lets the tests use `#[derive(LenientFromStr)]`, whose output refers to `::lenient_bool`.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as lenient_bool;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct LenientBoolError(());
+use core::fmt;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Not, Range,
+};
+use core::str::FromStr;
 
-impl FromStr for LenientBool {
-    type Err = LenientBoolError;
-    fn from_str(s: &str) -> Result<Self, LenientBoolError> {
-        if s.eq_ignore_ascii_case("true")
-        || s.eq_ignore_ascii_case("t")
-        || s.eq_ignore_ascii_case("yes")
-        || s.eq_ignore_ascii_case("y")
-        || s == "1" {
-            Ok(LenientBool(true))
-        } else
-        if s.eq_ignore_ascii_case("false")
-        || s.eq_ignore_ascii_case("f")
-        || s.eq_ignore_ascii_case("no")
-        || s.eq_ignore_ascii_case("n")
-        || s == "0" {
-            Ok(LenientBool(false))
-        } else {
-            Err(LenientBoolError(()))
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
+#[cfg(feature = "argh")]
+pub mod argh;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql;
+
+#[cfg(feature = "std")]
+mod bitstring;
+
+#[cfg(feature = "bitvec")]
+pub mod bitvec;
+
+#[cfg(feature = "bpaf")]
+pub mod bpaf;
+
+#[cfg(feature = "bson")]
+mod bson;
+
+#[cfg(feature = "calamine")]
+pub mod calamine;
+
+#[cfg(feature = "chumsky")]
+pub mod chumsky;
+
+#[cfg(feature = "clap")]
+pub mod clap;
+
+#[cfg(feature = "config")]
+pub mod config;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "serde")]
+mod deserializer;
+
+#[cfg(feature = "std")]
+pub mod detect;
+
+// Named so that it doesn't shadow the `diesel` paths generated by Diesel's derives.
+#[cfg(feature = "diesel")]
+mod diesel_types;
+
+mod enums;
+
+#[cfg(feature = "std")]
+pub mod env;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "figment")]
+pub mod figment;
+
+mod flag;
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub mod invariants;
+
+pub mod form;
+
+mod iter;
+
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "minijinja")]
+pub mod minijinja;
+
+#[cfg(feature = "mlua")]
+mod mlua;
+
+#[cfg(feature = "napi")]
+pub mod napi;
+
+#[cfg(feature = "nom")]
+pub mod nom;
+
+mod option;
+mod pack;
+#[cfg(feature = "std")]
+mod parser;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "quick-xml")]
+pub mod quick_xml;
+
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+
+#[cfg(feature = "redis")]
+mod redis;
+
+#[cfg(feature = "rhai")]
+pub mod rhai;
+
+#[cfg(feature = "rocket")]
+mod rocket;
+
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+
+#[cfg(feature = "serde")]
+pub mod extract;
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "std")]
+pub mod stream;
+
+#[cfg(feature = "tera")]
+pub mod tera;
+
+mod tristate;
+
+#[cfg(feature = "uniffi")]
+pub mod uniffi;
+
+#[cfg(feature = "uniffi")]
+::uniffi::setup_scaffolding!("lenient_bool");
+
+#[cfg(feature = "validator")]
+pub mod validator;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "winnow")]
+pub mod winnow;
+
+#[cfg(feature = "std")]
+pub use bitstring::{parse_bitstring, BitstringError, BitstringOptions};
+#[cfg(feature = "serde")]
+pub use deserializer::LenientBoolDeserializer;
+pub use enums::{match_token, ParseEnumError};
+pub use flag::LenientFlag;
+pub use iter::{
+    all_truthy, any_truthy, count_truthy, LenientBoolIterator, ParseLenientBools, Unparseable,
+};
+pub use option::LenientOptionBool;
+pub use pack::TokenPack;
+#[cfg(feature = "rayon")]
+pub use rayon::parse_all_par;
+pub use tristate::TriState;
+#[cfg(feature = "serde")]
+pub use serde::{
+    as_bool, as_bool_str, null_as_false, opt_as_bool, vec_as_bool, DefaultFalse, DefaultTrue,
+    LenientBoolDefaultFalse, LenientBoolDefaultTrue, LenientBoolOr, SerializeStyle,
+};
+/// Derives `FromStr` for an enum whose variants are parsed from lists of spellings, matched
+/// ignoring case the same way `LenientBool` matches its tokens.
+///
+/// Each variant takes its spellings from `#[lenient(tokens(...))]`, or is matched by its own
+/// name if it has none. Only variants without fields are supported. Unrecognized input is a
+/// `ParseEnumError`.
+///
+/// ```
+/// use lenient_bool::LenientFromStr;
+///
+/// #[derive(Debug, PartialEq, LenientFromStr)]
+/// enum Mode {
+///     #[lenient(tokens("on", "yes", "y", "ja"))]
+///     On,
+///     #[lenient(tokens("off", "no", "n"))]
+///     Off,
+///     Auto,
+/// }
+///
+/// assert_eq!("Ja".parse(), Ok(Mode::On));
+/// assert_eq!("AUTO".parse(), Ok(Mode::Auto));
+/// assert!("sometimes".parse::<Mode>().is_err());
+/// ```
+#[cfg(feature = "derive")]
+pub use lenient_bool_derive::LenientFromStr;
+#[cfg(feature = "std")]
+pub use parser::{
+    set_default_parser, CompiledParser, DefaultParserAlreadySet, FuzzyMatch, HelpStyle,
+    LenientBoolConfig, LenientBoolParser, Lint, NumericMode,
+};
+
+/// One of the spellings accepted by `LenientBool`.
+///
+/// Tokens are matched case-insensitively. `text` returns the lowercase form.
+///
+/// ```
+/// use lenient_bool::Token;
+///
+/// let truthy: Vec<&str> = Token::ALL
+///     .iter()
+///     .filter(|t| t.value())
+///     .map(|t| t.text())
+///     .collect();
+/// assert_eq!(truthy, ["true", "t", "yes", "y", "1"]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum Token {
+    True,
+    T,
+    Yes,
+    Y,
+    One,
+    False,
+    F,
+    No,
+    N,
+    Zero,
+}
+
+impl Token {
+    /// Every token, with the `true` tokens first. This includes tokens that are turned off
+    /// by the `tokens-*` features, which `is_enabled` checks for.
+    pub const ALL: &'static [Token] = &[
+        Token::True,
+        Token::T,
+        Token::Yes,
+        Token::Y,
+        Token::One,
+        Token::False,
+        Token::F,
+        Token::No,
+        Token::N,
+        Token::Zero,
+    ];
+
+    /// Whether `LenientBool` accepts this token, given the enabled `tokens-*` features.
+    ///
+    /// `true` and `false` are always accepted.
+    pub const fn is_enabled(self) -> bool {
+        match self {
+            Token::True | Token::False => true,
+            Token::Yes | Token::No => cfg!(feature = "tokens-yes-no"),
+            Token::T | Token::Y | Token::F | Token::N => cfg!(feature = "tokens-single-letter"),
+            Token::One | Token::Zero => cfg!(feature = "tokens-numeric"),
+        }
+    }
+
+    /// The boolean that this token parses to.
+    pub const fn value(self) -> bool {
+        matches!(self, Token::True | Token::T | Token::Yes | Token::Y | Token::One)
+    }
+
+    /// What kind of spelling this token is.
+    pub const fn class(self) -> TokenClass {
+        match self {
+            Token::True | Token::False | Token::Yes | Token::No => TokenClass::Word,
+            Token::T | Token::F | Token::Y | Token::N => TokenClass::SingleLetter,
+            Token::One | Token::Zero => TokenClass::Numeric,
+        }
+    }
+
+    /// The lowercase spelling of this token.
+    pub const fn text(self) -> &'static str {
+        match self {
+            Token::True => "true",
+            Token::T => "t",
+            Token::Yes => "yes",
+            Token::Y => "y",
+            Token::One => "1",
+            Token::False => "false",
+            Token::F => "f",
+            Token::No => "no",
+            Token::N => "n",
+            Token::Zero => "0",
         }
     }
 }
 
-impl From<LenientBool> for bool {
-    fn from(n: LenientBool) -> bool { n.0 }
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.text())
+    }
 }
 
-impl From<bool> for LenientBool {
-    fn from(b: bool) -> LenientBool { LenientBool(b) }
+/// The kinds of spelling that `LenientBool` accepts, reported by `LenientBool::parse_outcome`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum TokenClass {
+    /// A whole word, such as `true` or `no`.
+    Word,
+    /// A single letter, such as `t` or `N`.
+    SingleLetter,
+    /// `1` or `0`.
+    Numeric,
+    /// A word from one of the `locale-*` features, such as `ja` or `oui`.
+    Localized,
 }
 
-impl AsRef<bool> for LenientBool {
-    fn as_ref(&self) -> &bool {
-        &self.0
+/// A successful parse, with the token that matched, from `LenientBool::parse_outcome`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ParseOutcome {
+    pub value: bool,
+    /// The token that matched, in lowercase, such as `yes` for `YES`.
+    pub matched: &'static str,
+    pub class: TokenClass,
+}
+
+/// The spellings accepted as `true`, compared case-insensitively.
+pub(crate) const TRUE_TOKENS: &[&str] = &[
+    Token::True.text(),
+    #[cfg(feature = "tokens-single-letter")]
+    Token::T.text(),
+    #[cfg(feature = "tokens-yes-no")]
+    Token::Yes.text(),
+    #[cfg(feature = "tokens-single-letter")]
+    Token::Y.text(),
+    #[cfg(feature = "tokens-numeric")]
+    Token::One.text(),
+    // The localized tokens match `TokenPack::yes_no`.
+    #[cfg(feature = "locale-de")]
+    "ja",
+    #[cfg(feature = "locale-es")]
+    "sí",
+    #[cfg(feature = "locale-es")]
+    "si",
+    #[cfg(feature = "locale-fr")]
+    "oui",
+    #[cfg(feature = "locale-ja")]
+    "はい",
+];
+
+/// The spellings accepted as `false`, compared case-insensitively.
+pub(crate) const FALSE_TOKENS: &[&str] = &[
+    Token::False.text(),
+    #[cfg(feature = "tokens-single-letter")]
+    Token::F.text(),
+    #[cfg(feature = "tokens-yes-no")]
+    Token::No.text(),
+    #[cfg(feature = "tokens-single-letter")]
+    Token::N.text(),
+    #[cfg(feature = "tokens-numeric")]
+    Token::Zero.text(),
+    #[cfg(feature = "locale-de")]
+    "nein",
+    #[cfg(all(feature = "locale-es", not(feature = "tokens-yes-no")))]
+    "no",
+    #[cfg(feature = "locale-fr")]
+    "non",
+    #[cfg(feature = "locale-ja")]
+    "いいえ",
+];
+
+/// A human-readable error message listing the accepted values.
+#[cfg(any(feature = "bpaf", feature = "chumsky"))]
+pub(crate) fn expected_message() -> String {
+    format!("expected one of {}, {}", TRUE_TOKENS.join(", "), FALSE_TOKENS.join(", "))
+}
+
+/// Matches the longest token at the start of `input`, returning its value and length.
+///
+/// A token only matches when it isn't followed by another word character, so `yesterday`
+/// doesn't match `yes`.
+pub(crate) fn parse_prefix(input: &str) -> Option<(bool, usize)> {
+    Token::ALL
+        .iter()
+        .filter(|t| t.is_enabled())
+        .filter(|t| {
+            let text = t.text();
+            let matches = input
+                .get(..text.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(text));
+            matches
+                && input[text.len()..]
+                    .chars()
+                    .next()
+                    .is_none_or(|c| !c.is_alphanumeric() && c != '_')
+        })
+        .max_by_key(|t| t.text().len())
+        .map(|t| (t.value(), t.text().len()))
+}
+
+/// How many spellings `LenientBool::from_str_with` accepts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Strictness {
+    /// Only `true` and `false`.
+    Strict,
+    /// The usual `LenientBool` rules.
+    #[default]
+    Standard,
+    /// The usual rules, plus the `TokenPack::ON_OFF` and `TokenPack::ENABLE_DISABLE`
+    /// spellings.
+    Extended,
+}
+
+/// A convention for writing a boolean back out, used by `LenientBool::format_as`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum BoolStyle {
+    /// `true` and `false`.
+    #[default]
+    TrueFalse,
+    /// `yes` and `no`.
+    YesNo,
+    /// `on` and `off`.
+    OnOff,
+    /// `1` and `0`.
+    Numeric,
+    /// `t` and `f`.
+    TF,
+    /// `y` and `n`.
+    YN,
+}
+
+impl BoolStyle {
+    /// The text for `value` in this style.
+    pub const fn text(self, value: bool) -> &'static str {
+        let (true_text, false_text) = match self {
+            BoolStyle::TrueFalse => ("true", "false"),
+            BoolStyle::YesNo => ("yes", "no"),
+            BoolStyle::OnOff => ("on", "off"),
+            BoolStyle::Numeric => ("1", "0"),
+            BoolStyle::TF => ("t", "f"),
+            BoolStyle::YN => ("y", "n"),
+        };
+        if value { true_text } else { false_text }
     }
 }
 
-impl AsMut<bool> for LenientBool {
-    fn as_mut(&mut self) -> &mut bool {
-        &mut self.0
+/// The extra spellings accepted by `Strictness::Extended`.
+pub(crate) const EXTENDED_PACKS: &[TokenPack] = &[TokenPack::ON_OFF, TokenPack::ENABLE_DISABLE];
+
+/// Folds a character for case-insensitive comparison.
+///
+/// This is lowercasing, except that the Turkish `İ` and `ı` both fold to `i` and the long
+/// `ſ` folds to `s`, so that values typed with a Turkish keyboard layout or with lookalike
+/// letters still match. With the `fullwidth` feature, full-width forms such as `Ｙ` fold
+/// like their ASCII equivalents.
+pub(crate) fn fold(c: char) -> impl Iterator<Item = char> {
+    #[cfg(feature = "fullwidth")]
+    let c = match c {
+        '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        _ => c,
+    };
+    let special = match c {
+        'İ' | 'ı' => Some('i'),
+        'ſ' => Some('s'),
+        _ => None,
+    };
+    let lower = match special {
+        Some(_) => None,
+        None => Some(c.to_lowercase()),
+    };
+    special.into_iter().chain(lower.into_iter().flatten())
+}
+
+/// Compares two strings case-insensitively, including non-ASCII letters.
+pub(crate) fn eq_ignore_case(a: &str, b: &str) -> bool {
+    // `b` is usually a short token, so it is checked first, and `a` only when the lengths
+    // agree, so that long input isn't scanned for every token it's compared to.
+    if b.is_ascii() && a.len() == b.len() && a.is_ascii() {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        // This stops at the first difference.
+        a.chars().flat_map(fold).eq(b.chars().flat_map(fold))
     }
 }
 
-impl Deref for LenientBool {
-    type Target = bool;
-    fn deref(&self) -> &Self::Target {
-        &self.0
+/// Compares input to a token case-insensitively.
+fn matches_token(s: &[u8], token: &str) -> bool {
+    if s.is_ascii() && token.is_ascii() {
+        s.eq_ignore_ascii_case(token.as_bytes())
+    } else {
+        core::str::from_utf8(s).is_ok_and(|s| eq_ignore_case(s, token))
     }
 }
 
-impl DerefMut for LenientBool {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+/// Compares bytes ignoring ASCII case, in a `const` context.
+const fn const_eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
     }
+    true
 }
 
-#[cfg(test)]
-mod test {
-    pub use super::*;
+const fn const_matches_any(s: &[u8], tokens: &[&str]) -> bool {
+    let mut i = 0;
+    while i < tokens.len() {
+        if const_eq_ignore_ascii_case(s, tokens[i].as_bytes()) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
 
-    #[test]
-    fn parse_f() {
-        assert_eq!("f".parse::<LenientBool>(), Ok(LenientBool(false)));
+/// The length of the longest ASCII token. ASCII input up to this length is lowercased into a
+/// buffer on the stack once, rather than once per token, and longer ASCII input can't match
+/// an ASCII token at all.
+const MAX_ASCII_TOKEN_LEN: usize = 16;
+
+fn match_tokens(
+    s: &[u8],
+    true_tokens: &[&'static str],
+    false_tokens: &[&'static str],
+) -> Option<bool> {
+    find_token(s, true_tokens, false_tokens).map(|(_, value)| value)
+}
+
+/// Finds the token that `s` matches, and its value.
+fn find_token(
+    s: &[u8],
+    true_tokens: &[&'static str],
+    false_tokens: &[&'static str],
+) -> Option<(&'static str, bool)> {
+    if !s.is_ascii() {
+        return find_value(true_tokens, false_tokens, |t| matches_token(s, t));
     }
 
-    #[test]
-    fn parse_f_upper() {
-        assert_eq!("F".parse::<LenientBool>(), Ok(LenientBool(false)));
+    let mut buf = [0; MAX_ASCII_TOKEN_LEN];
+    let lower = buf.get_mut(..s.len()).map(|lower| {
+        for (l, b) in lower.iter_mut().zip(s) {
+            *l = b.to_ascii_lowercase();
+        }
+        &*lower
+    });
+    // The ASCII tokens are all lowercase, so they can be compared to `lower` directly.
+    find_value(true_tokens, false_tokens, |t| {
+        if t.is_ascii() {
+            lower == Some(t.as_bytes())
+        } else {
+            matches_token(s, t)
+        }
+    })
+}
+
+fn find_value(
+    true_tokens: &[&'static str],
+    false_tokens: &[&'static str],
+    matches: impl Fn(&str) -> bool,
+) -> Option<(&'static str, bool)> {
+    let find = |tokens: &[&'static str]| tokens.iter().copied().find(|t| matches(t));
+    find(true_tokens)
+        .map(|t| (t, true))
+        .or_else(|| find(false_tokens).map(|t| (t, false)))
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = ::diesel::sql_types::Bool))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = ::diesel::sql_types::Text))]
+pub struct LenientBool(pub bool);
+
+impl LenientBool {
+    /// The spellings that parse as `true`, in lowercase.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// let options: Vec<&str> = LenientBool::truthy_tokens().collect();
+    /// assert!(options.starts_with(&["true", "t", "yes", "y", "1"]));
+    /// ```
+    pub fn truthy_tokens() -> impl Iterator<Item = &'static str> {
+        TRUE_TOKENS.iter().copied()
     }
 
-    #[test]
-    fn parse_false() {
-        assert_eq!("false".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// The spellings that parse as `false`, in lowercase.
+    pub fn falsy_tokens() -> impl Iterator<Item = &'static str> {
+        FALSE_TOKENS.iter().copied()
     }
 
-    #[test]
-    fn parse_false_cap() {
-        assert_eq!("False".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses `s` with the given `Strictness`, for call sites that need more or less
+    /// tolerance than usual.
+    ///
+    /// `Strictness::Standard` is the same as `str::parse`.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBool, Strictness};
+    ///
+    /// assert!(LenientBool::from_str_with("yes", Strictness::Strict).is_err());
+    /// assert_eq!(LenientBool::from_str_with("yes", Strictness::Standard), Ok(LenientBool(true)));
+    /// assert_eq!(LenientBool::from_str_with("OFF", Strictness::Extended), Ok(LenientBool(false)));
+    /// ```
+    pub fn from_str_with(
+        s: &str,
+        strictness: Strictness,
+    ) -> Result<LenientBool, LenientBoolError> {
+        let value = match strictness {
+            Strictness::Strict => match_tokens(s.as_bytes(), &[Token::True.text()], &[Token::False.text()]),
+            Strictness::Standard => return s.parse(),
+            Strictness::Extended => s
+                .parse::<LenientBool>()
+                .ok()
+                .map(bool::from)
+                .or_else(|| {
+                    EXTENDED_PACKS
+                        .iter()
+                        .find_map(|p| match_tokens(s.as_bytes(), p.true_tokens(), p.false_tokens()))
+                }),
+        };
+        value.map(LenientBool).ok_or_else(|| LenientBoolError::new(s))
     }
 
-    #[test]
-    fn parse_false_upper() {
-        assert_eq!("FALSE".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses raw bytes with the same rules as `FromStr`, without checking that they are
+    /// UTF-8 first.
+    ///
+    /// The tokens are all ASCII, so this matches bytes directly, which suits network protocol
+    /// fields and CSV buffers. When a parser set with `LenientBool::with_parser` or
+    /// `set_default_parser` is active, or when the `nfkc` feature has to normalize non-ASCII
+    /// input, the bytes do have to be UTF-8.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool::from_bytes(b"Yes"), Ok(LenientBool(true)));
+    /// assert!(LenientBool::from_bytes(b"\xffno").is_err());
+    /// ```
+    pub fn from_bytes(b: &[u8]) -> Result<LenientBool, LenientBoolError> {
+        #[cfg(feature = "std")]
+        if parser::with_current(|p| p.is_some()) || (cfg!(feature = "nfkc") && !b.is_ascii()) {
+            return match core::str::from_utf8(b) {
+                Ok(s) => s.parse(),
+                Err(_) => Err(LenientBoolError::from_bytes(b)),
+            };
+        }
+
+        match_tokens(b, TRUE_TOKENS, FALSE_TOKENS)
+            .map(LenientBool)
+            .ok_or_else(|| LenientBoolError::from_bytes(b))
     }
 
-    #[test]
-    fn parse_n() {
-        assert_eq!("n".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses an `OsStr`, such as a value from `std::env::var_os` or a raw command-line
+    /// argument, without converting it to a `str` first.
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool::from_os_str(OsStr::new("no")), Ok(LenientBool(false)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_os_str(s: &std::ffi::OsStr) -> Result<LenientBool, LenientBoolError> {
+        LenientBool::from_bytes(s.as_encoded_bytes())
     }
 
-    #[test]
-    fn parse_n_upper() {
-        assert_eq!("N".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses a single character, such as a flag in a fixed-width record, with the same rules
+    /// as `FromStr` and without allocating.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool::from_char('Y'), Ok(LenientBool(true)));
+    /// assert_eq!(LenientBool::from_char('0'), Ok(LenientBool(false)));
+    /// assert!(LenientBool::from_char('x').is_err());
+    /// ```
+    pub fn from_char(c: char) -> Result<LenientBool, LenientBoolError> {
+        c.encode_utf8(&mut [0; 4]).parse()
     }
 
-    #[test]
-    fn parse_no() {
-        assert_eq!("no".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses `s` after trimming whitespace from both ends, including the `\r\n` of
+    /// Windows line endings.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool::from_str_trimmed(" true\r\n"), Ok(LenientBool(true)));
+    /// assert!(" true".parse::<LenientBool>().is_err());
+    /// ```
+    pub fn from_str_trimmed(s: &str) -> Result<LenientBool, LenientBoolError> {
+        s.trim().parse()
     }
 
-    #[test]
-    fn parse_no_cap() {
-        assert_eq!("No".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses `s`, returning `default` if it isn't a recognized boolean.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert!(!LenientBool::parse_or("no", true));
+    /// assert!(LenientBool::parse_or("maybe", true));
+    /// ```
+    pub fn parse_or(s: &str, default: bool) -> bool {
+        s.parse::<LenientBool>().map_or(default, bool::from)
     }
 
-    #[test]
-    fn parse_no_upper() {
-        assert_eq!("NO".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses `s`, returning `false` if it isn't a recognized boolean.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert!(LenientBool::parse_or_default("Y"));
+    /// assert!(!LenientBool::parse_or_default("maybe"));
+    /// ```
+    pub fn parse_or_default(s: &str) -> bool {
+        LenientBool::parse_or(s, false)
     }
 
-    #[test]
-    fn parse_0() {
-        assert_eq!("0".parse::<LenientBool>(), Ok(LenientBool(false)));
+    /// Parses `s` with the default tokens in a `const` context, such as a `const` item or a
+    /// value generated by a build script.
+    ///
+    /// This agrees with `FromStr` for ASCII input. Non-ASCII input only matches a token
+    /// exactly, since Unicode case folding isn't available in `const` code, and a parser set
+    /// with `LenientBool::with_parser` or `set_default_parser` is never used.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// const VERBOSE: Option<LenientBool> = LenientBool::const_parse("Yes");
+    /// assert_eq!(VERBOSE, Some(LenientBool(true)));
+    /// assert_eq!(LenientBool::const_parse("maybe"), None);
+    /// ```
+    pub const fn const_parse(s: &str) -> Option<LenientBool> {
+        let s = s.as_bytes();
+        if const_matches_any(s, TRUE_TOKENS) {
+            Some(LenientBool(true))
+        } else if const_matches_any(s, FALSE_TOKENS) {
+            Some(LenientBool(false))
+        } else {
+            None
+        }
     }
 
-    #[test]
-    fn parse_t() {
-        assert_eq!("t".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// Parses `s` like `FromStr`, also reporting which token matched and what kind of
+    /// spelling it is, for data-quality reports that track how values were written.
+    ///
+    /// This always uses the default rules, even when a parser set with
+    /// `LenientBool::with_parser` or `set_default_parser` is active.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBool, ParseOutcome, TokenClass};
+    ///
+    /// assert_eq!(
+    ///     LenientBool::parse_outcome("Y"),
+    ///     Ok(ParseOutcome { value: true, matched: "y", class: TokenClass::SingleLetter })
+    /// );
+    /// assert_eq!(LenientBool::parse_outcome("0").unwrap().class, TokenClass::Numeric);
+    /// ```
+    pub fn parse_outcome(s: &str) -> Result<ParseOutcome, LenientBoolError> {
+        #[cfg(feature = "nfkc")]
+        let normalized: String = {
+            use ::unicode_normalization::UnicodeNormalization;
+            s.nfkc().collect()
+        };
+        #[cfg(feature = "nfkc")]
+        let input = normalized.as_bytes();
+        #[cfg(not(feature = "nfkc"))]
+        let input = s.as_bytes();
+
+        let (matched, value) =
+            find_token(input, TRUE_TOKENS, FALSE_TOKENS).ok_or_else(|| LenientBoolError::new(s))?;
+        let class = Token::ALL
+            .iter()
+            .find(|t| t.text() == matched)
+            .map_or(TokenClass::Localized, |t| t.class());
+        Ok(ParseOutcome { value, matched, class })
     }
 
-    #[test]
-    fn parse_t_upper() {
-        assert_eq!("T".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// Converts an integer flag, with `0` as `false` and anything else as `true`, like C.
+    ///
+    /// `TryFrom` is the strict alternative, which only accepts `0` and `1`.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool::from_int_lenient(-1), LenientBool(true));
+    /// assert_eq!(LenientBool::from_int_lenient(0), LenientBool(false));
+    /// assert!(LenientBool::try_from(2u8).is_err());
+    /// assert_eq!(LenientBool::try_from(1i64), Ok(LenientBool(true)));
+    /// ```
+    pub const fn from_int_lenient(i: i64) -> LenientBool {
+        LenientBool(i != 0)
     }
 
-    #[test]
-    fn parse_true() {
-        assert_eq!("true".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// Guesses a boolean from any string, without ever failing.
+    ///
+    /// Only the first character after any leading whitespace is considered: `y`, `t`, `1`,
+    /// and `+` mean `true`, in either case, and anything else, including an empty string,
+    /// means `false`. This misclassifies some values, such as `+0`, but is useful where
+    /// dropping a record is worse than occasionally getting it wrong.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert!(LenientBool::parse_heuristic("Yep"));
+    /// assert!(LenientBool::parse_heuristic("+"));
+    /// assert!(!LenientBool::parse_heuristic("nope"));
+    /// assert!(!LenientBool::parse_heuristic(""));
+    /// ```
+    pub fn parse_heuristic(s: &str) -> bool {
+        matches!(
+            s.trim_start().chars().next(),
+            Some('y' | 'Y' | 't' | 'T' | '1' | '+')
+        )
     }
 
-    #[test]
-    fn parse_true_cap() {
-        assert_eq!("True".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// Matches the longest token at the start of `s`, returning its value and the rest of
+    /// the string, for tokenizers and query-string parsers that find booleans inside larger
+    /// input.
+    ///
+    /// A token only matches when it isn't followed by a letter, digit, or `_`, so `yesterday`
+    /// doesn't match `yes`. Only the ASCII tokens are matched, and leading whitespace isn't
+    /// skipped.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool::parse_prefix("yes&debug=1"), Some((LenientBool(true), "&debug=1")));
+    /// assert_eq!(LenientBool::parse_prefix("F)"), Some((LenientBool(false), ")")));
+    /// assert_eq!(LenientBool::parse_prefix("yesterday"), None);
+    /// ```
+    pub fn parse_prefix(s: &str) -> Option<(LenientBool, &str)> {
+        parse_prefix(s).map(|(value, len)| (LenientBool(value), &s[len..]))
     }
 
-    #[test]
-    fn parse_true_upper() {
-        assert_eq!("TRUE".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// `"true"` or `"false"`, without allocating.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool(true).as_str(), "true");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        self.format_as(BoolStyle::TrueFalse)
     }
 
-    #[test]
-    fn parse_y() {
-        assert_eq!("y".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// Renders the value in the given `BoolStyle`, for writing to a system that expects a
+    /// particular convention. `Display` uses `BoolStyle::TrueFalse`.
+    ///
+    /// ```
+    /// use lenient_bool::{BoolStyle, LenientBool};
+    ///
+    /// assert_eq!(LenientBool(true).format_as(BoolStyle::YesNo), "yes");
+    /// assert_eq!(LenientBool(false).format_as(BoolStyle::Numeric), "0");
+    /// assert_eq!(LenientBool(false).to_string(), "false");
+    /// ```
+    pub const fn format_as(self, style: BoolStyle) -> &'static str {
+        style.text(self.0)
     }
 
-    #[test]
-    fn parse_y_upper() {
-        assert_eq!("Y".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// Parses a list of values separated by `delimiter`, ignoring whitespace around each item.
+    ///
+    /// An empty or all-whitespace input is an empty list, and a single trailing delimiter is
+    /// ignored. An empty item anywhere else is an error. Every item that fails to parse is
+    /// reported in the error, not just the first one.
+    ///
+    /// `lenient_bool::parse_list` does the same, returning `LenientBool`s.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBool;
+    ///
+    /// assert_eq!(LenientBool::parse_list("yes, no ,1", ','), Ok(vec![true, false, true]));
+    ///
+    /// let err = LenientBool::parse_list("yes,maybe", ',').unwrap_err();
+    /// assert_eq!(err.items[0].index, 1);
+    /// assert_eq!(err.items[0].offset, 4);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_list(s: &str, delimiter: char) -> Result<Vec<bool>, ListError> {
+        parse_list(s, delimiter).map(|values| values.into_iter().map(bool::from).collect())
     }
+}
 
-    #[test]
-    fn parse_yes() {
-        assert_eq!("yes".parse::<LenientBool>(), Ok(LenientBool(true)));
+/// Parses a list of values separated by `delimiter`, as `LenientBool::parse_list` does.
+///
+/// ```
+/// use lenient_bool::LenientBool;
+///
+/// let values = lenient_bool::parse_list("yes, no, 1,", ',').unwrap();
+/// assert_eq!(values, [LenientBool(true), LenientBool(false), LenientBool(true)]);
+///
+/// let err = lenient_bool::parse_list("yes,,no", ',').unwrap_err();
+/// assert_eq!(err.items[0].index, 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_list(s: &str, delimiter: char) -> Result<Vec<LenientBool>, ListError> {
+    let s = s.trim_end();
+    let s = s.strip_suffix(delimiter).unwrap_or(s);
+    if s.trim().is_empty() {
+        return Ok(Vec::new());
     }
 
-    #[test]
-    fn parse_yes_cap() {
-        assert_eq!("Yes".parse::<LenientBool>(), Ok(LenientBool(true)));
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    let mut offset = 0;
+    for (index, item) in s.split(delimiter).enumerate() {
+        let trimmed = item.trim_start();
+        let item_offset = offset + (item.len() - trimmed.len());
+        let trimmed = trimmed.trim_end();
+        match trimmed.parse::<LenientBool>() {
+            Ok(b) => values.push(b),
+            Err(_) => errors.push(ListItemError {
+                index,
+                offset: item_offset,
+                value: trimmed.to_owned(),
+            }),
+        }
+        offset += item.len() + delimiter.len_utf8();
     }
 
-    #[test]
-    fn parse_yes_upper() {
-        assert_eq!("YES".parse::<LenientBool>(), Ok(LenientBool(true)));
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(ListError { items: errors })
     }
+}
 
-    #[test]
-    fn parse_1() {
-        assert_eq!("1".parse::<LenientBool>(), Ok(LenientBool(true)));
+/// Finds the first boolean token that stands on its own in a larger string, returning its
+/// value and its byte range in `s`.
+///
+/// A token only counts when it isn't part of a longer word, so the `no` in `notable` and the
+/// `yes` in `eyes` are passed over. Single-character spellings count too, so the `n` of
+/// `n/a` is found as `false`.
+///
+/// ```
+/// let s = "answer: yes (confirmed)";
+/// assert_eq!(lenient_bool::extract(s), Some((true, 8..11)));
+///
+/// assert_eq!(lenient_bool::extract("a notable event, FALSE alarm"), Some((false, 17..22)));
+/// assert_eq!(lenient_bool::extract("nothing to see"), None);
+/// ```
+pub fn extract(s: &str) -> Option<(bool, Range<usize>)> {
+    let mut previous = None;
+    for (start, c) in s.char_indices() {
+        let at_boundary = previous.is_none_or(|p: char| !p.is_alphanumeric() && p != '_');
+        if at_boundary {
+            if let Some((value, len)) = parse_prefix(&s[start..]) {
+                return Some((value, start..start + len));
+            }
+        }
+        previous = Some(c);
     }
+    None
+}
 
-    mod errors {
-        use super::*;
+/// The error returned when a string isn't a recognized boolean.
+///
+/// The error keeps a copy of the input so that it can be reported. Long inputs are truncated
+/// to `LenientBoolError::MAX_INPUT_LEN` bytes, which keeps the error small and `Copy` and
+/// lets it work without an allocator.
+///
+/// ```
+/// use lenient_bool::LenientBool;
+///
+/// let err = "maybe".parse::<LenientBool>().unwrap_err();
+/// assert_eq!(err.input(), "maybe");
+/// assert_eq!(err.to_string(), r#"invalid boolean value "maybe""#);
+/// ```
+///
+/// The alternate form, `{:#}`, is meant for showing to users. It suggests the closest
+/// accepted value, if there is one, and lists the accepted values.
+///
+/// ```
+/// use lenient_bool::LenientBool;
+///
+/// let err = "treu".parse::<LenientBool>().unwrap_err();
+/// assert_eq!(err.suggestion(), Some("true"));
+/// assert!(format!("{:#}", err).starts_with(
+///     r#"invalid boolean value "treu", did you mean "true"? Expected one of: true, t, yes, y, 1"#
+/// ));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LenientBoolError {
+    input: [u8; LenientBoolError::MAX_INPUT_LEN],
+    len: u8,
+    truncated: bool,
+}
 
-        #[test]
-        fn parse_empty_err() {
-            assert_eq!("".parse::<LenientBool>(), Err(LenientBoolError(())));
-        }
+impl LenientBoolError {
+    /// The longest input, in bytes, that an error keeps in full.
+    pub const MAX_INPUT_LEN: usize = 64;
 
-        #[test]
-        fn parse_bad_input_err() {
-            assert_eq!("abc".parse::<LenientBool>(), Err(LenientBoolError(())));
+    pub(crate) fn new(input: &str) -> LenientBoolError {
+        let mut len = input.len().min(LenientBoolError::MAX_INPUT_LEN);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut buf = [0; LenientBoolError::MAX_INPUT_LEN];
+        buf[..len].copy_from_slice(&input.as_bytes()[..len]);
+        LenientBoolError {
+            input: buf,
+            len: len as u8,
+            truncated: len < input.len(),
         }
     }
 
-    mod conversion {
-        use super::*;
+    /// Like `new`, for input that may not be UTF-8. Only the leading valid UTF-8 is kept.
+    pub(crate) fn from_bytes(input: &[u8]) -> LenientBoolError {
+        let valid = match core::str::from_utf8(input) {
+            Ok(s) => s,
+            Err(e) => core::str::from_utf8(&input[..e.valid_up_to()]).unwrap_or_default(),
+        };
+        let mut err = LenientBoolError::new(valid);
+        err.truncated |= valid.len() < input.len();
+        err
+    }
 
-        #[test]
-        fn from() {
-            let lb = "true".parse::<LenientBool>();
-            assert!(lb.is_ok());
-            let lb = lb.unwrap();
+    /// Like `new`, for a value that isn't a string, such as an out-of-range integer.
+    pub(crate) fn from_display(value: impl fmt::Display) -> LenientBoolError {
+        struct Buf {
+            bytes: [u8; LenientBoolError::MAX_INPUT_LEN],
+            len: usize,
+            truncated: bool,
+        }
 
-            // The preferred way.
-            let b : bool = lb.into();
-            assert_eq!(b, true);
+        impl fmt::Write for Buf {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let mut n = s.len().min(self.bytes.len() - self.len);
+                while !s.is_char_boundary(n) {
+                    n -= 1;
+                }
+                self.bytes[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+                self.len += n;
+                self.truncated |= n < s.len();
+                Ok(())
+            }
+        }
 
-            let lb = "false".parse::<LenientBool>();
-            assert!(lb.is_ok());
-            let lb = lb.unwrap();
+        let mut buf = Buf {
+            bytes: [0; LenientBoolError::MAX_INPUT_LEN],
+            len: 0,
+            truncated: false,
+        };
+        let _ = fmt::write(&mut buf, format_args!("{}", value));
+        let mut err = LenientBoolError::from_bytes(&buf.bytes[..buf.len]);
+        err.truncated |= buf.truncated;
+        err
+    }
 
-            // The preferred way.
-            let b : bool = lb.into();
-            assert_eq!(b, false);
-        }
+    /// The input that failed to parse, truncated if it was longer than `MAX_INPUT_LEN`.
+    pub fn input(&self) -> &str {
+        // Only whole characters of a `str` are ever copied in.
+        core::str::from_utf8(&self.input[..self.len as usize]).unwrap_or_default()
+    }
 
-        #[test]
-        fn deref() {
-            let lb = "true".parse::<LenientBool>();
-            assert!(lb.is_ok());
-            let lb = lb.unwrap();
+    /// Whether `input` was cut short.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
 
-            assert_eq!(*lb, true);
+    /// The default token closest to the input, for a "did you mean" hint.
+    ///
+    /// A token is only suggested when the input is at most two edits from it and has fewer
+    /// edits than the token has characters, so `treu` suggests `true` but `maybe` suggests
+    /// nothing. Only the default tokens are considered, whichever parser produced the error.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        if self.truncated {
+            return None;
+        }
+        let mut best: Option<(&'static str, usize)> = None;
+        for token in TRUE_TOKENS.iter().chain(FALSE_TOKENS) {
+            let Some(distance) = edit_distance_ignore_case(self.input(), token) else {
+                continue;
+            };
+            // An exact match was rejected by a stricter parser, so suggesting it won't help.
+            if distance == 0 || distance > 2 || distance >= token.chars().count() {
+                continue;
+            }
+            if best.is_none_or(|(_, d)| distance < d) {
+                best = Some((token, distance));
+            }
+        }
+        best.map(|(token, _)| token)
+    }
+}
 
-            let lb = "false".parse::<LenientBool>();
-            assert!(lb.is_ok());
-            let lb = lb.unwrap();
+/// The optimal string alignment distance between `a` and `b`, ignoring case, or `None` if
+/// `b` folds to more than `MAX_ASCII_TOKEN_LEN` characters. This doesn't allocate, so that
+/// suggestions work without `std`.
+fn edit_distance_ignore_case(a: &str, b: &str) -> Option<usize> {
+    const N: usize = MAX_ASCII_TOKEN_LEN + 1;
+    let mut buf = ['\0'; MAX_ASCII_TOKEN_LEN];
+    let mut len = 0;
+    for c in b.chars().flat_map(fold) {
+        *buf.get_mut(len)? = c;
+        len += 1;
+    }
+    let b = &buf[..len];
 
-            assert_eq!(*lb, false);
+    // Only the last two rows of the table are needed.
+    let mut before: [usize; N] = core::array::from_fn(|j| j);
+    let mut prev = before;
+    let mut prev_c = None;
+    for (i, c) in a.chars().flat_map(fold).enumerate() {
+        let mut row = [i + 1; N];
+        for j in 1..=len {
+            let cost = usize::from(c != b[j - 1]);
+            row[j] = (prev[j] + 1).min(row[j - 1] + 1).min(prev[j - 1] + cost);
+            if j > 1 && c == b[j - 2] && prev_c == Some(b[j - 1]) {
+                row[j] = row[j].min(before[j - 2] + 1);
+            }
+        }
+        before = prev;
+        prev = row;
+        prev_c = Some(c);
+    }
+    Some(prev[len])
+}
+
+impl fmt::Debug for LenientBoolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LenientBoolError")
+            .field("input", &self.input())
+            .field("truncated", &self.truncated)
+            .finish()
+    }
+}
+
+impl fmt::Display for LenientBoolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid boolean value {:?}", self.input())?;
+        if self.truncated {
+            f.write_str(" (truncated)")?;
+        }
+        if !f.alternate() {
+            return Ok(());
+        }
+        match self.suggestion() {
+            Some(suggestion) => write!(f, ", did you mean {:?}?", suggestion)?,
+            None => f.write_str(".")?,
+        }
+        f.write_str(" Expected one of: ")?;
+        for (i, token) in TRUE_TOKENS.iter().chain(FALSE_TOKENS).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(token)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LenientBoolError {}
+
+/// The items that failed to parse in `LenientBool::parse_list`.
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ListError {
+    pub items: Vec<ListItemError>,
+}
+
+/// An item that failed to parse in `LenientBool::parse_list`.
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ListItemError {
+    /// The 0-based position of the item in the list.
+    pub index: usize,
+    /// The byte offset of the item in the input, after leading whitespace.
+    pub offset: usize,
+    /// The item, with surrounding whitespace removed.
+    pub value: String,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ListItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid boolean value {:?} in item {} at offset {}",
+            self.value, self.index, self.offset
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ListError {}
+
+/// Parses every value in `values`, such as the rows of an uploaded column.
+///
+/// Every value that fails to parse is reported in the error along with its index, so that
+/// the bad rows can be pointed out.
+///
+/// ```
+/// let err = lenient_bool::parse_all(&["yes", "maybe", "0", "?"]).unwrap_err();
+/// assert_eq!(err.items[0].index, 1);
+/// assert_eq!(err.items[1].value, "?");
+///
+/// assert_eq!(lenient_bool::parse_all(&["t", "F"]), Ok(vec![true, false]));
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_all<S: AsRef<str>>(values: &[S]) -> Result<Vec<bool>, BatchError> {
+    let mut parsed = Vec::with_capacity(values.len());
+    let mut errors = Vec::new();
+    for (index, value) in values.iter().enumerate() {
+        match value.as_ref().parse::<LenientBool>() {
+            Ok(b) => parsed.push(b.0),
+            Err(_) => errors.push(BatchItemError { index, value: value.as_ref().to_owned() }),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(BatchError { items: errors })
+    }
+}
+
+/// The values that failed to parse in `parse_all`.
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BatchError {
+    pub items: Vec<BatchItemError>,
+}
+
+/// A value that failed to parse in `parse_all`.
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BatchItemError {
+    /// The 0-based position of the value in the slice.
+    pub index: usize,
+    /// The value.
+    pub value: String,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for BatchItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid boolean value {:?} at index {}", self.value, self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+/// Parses a string at compile time, expanding to `true` or `false`.
+///
+/// This uses `LenientBool::const_parse`, so an unrecognized value fails the build instead of
+/// failing at runtime. It is meant for values pasted into generated code, where the source
+/// may spell booleans in many ways.
+///
+/// ```
+/// use lenient_bool::lenient_bool;
+///
+/// const FEATURE_ENABLED: bool = lenient_bool!("Yes");
+/// assert!(FEATURE_ENABLED);
+/// assert!(!lenient_bool!("0"));
+/// ```
+///
+/// ```compile_fail
+/// let value = lenient_bool::lenient_bool!("maybe");
+/// ```
+#[macro_export]
+macro_rules! lenient_bool {
+    ($s:expr) => {{
+        const VALUE: bool = match $crate::LenientBool::const_parse($s) {
+            ::core::option::Option::Some(value) => value.0,
+            ::core::option::Option::None => {
+                panic!(concat!("unrecognized boolean value: ", stringify!($s)))
+            }
+        };
+        VALUE
+    }};
+}
+
+impl FromStr for LenientBool {
+    type Err = LenientBoolError;
+    fn from_str(s: &str) -> Result<Self, LenientBoolError> {
+        #[cfg(feature = "std")]
+        if let Some(result) = parser::with_current(|p| p.map(|p| p.parse(s))) {
+            return result.map(LenientBool);
+        }
+
+        #[cfg(feature = "nfkc")]
+        if !s.is_ascii() {
+            use ::unicode_normalization::UnicodeNormalization;
+            let normalized: String = s.nfkc().collect();
+            return match_tokens(normalized.as_bytes(), TRUE_TOKENS, FALSE_TOKENS)
+                .map(LenientBool)
+                .ok_or_else(|| LenientBoolError::new(s));
+        }
+
+        match_tokens(s.as_bytes(), TRUE_TOKENS, FALSE_TOKENS)
+            .map(LenientBool)
+            .ok_or_else(|| LenientBoolError::new(s))
+    }
+}
+
+/// Parses bytes with `LenientBool::from_bytes`.
+impl<'a> TryFrom<&'a [u8]> for LenientBool {
+    type Error = LenientBoolError;
+
+    fn try_from(b: &'a [u8]) -> Result<LenientBool, LenientBoolError> {
+        LenientBool::from_bytes(b)
+    }
+}
+
+/// Parses a character with `LenientBool::from_char`.
+impl TryFrom<char> for LenientBool {
+    type Error = LenientBoolError;
+
+    fn try_from(c: char) -> Result<LenientBool, LenientBoolError> {
+        LenientBool::from_char(c)
+    }
+}
+
+/// Parses a string with `FromStr`, for generic code bounded on `TryFrom`.
+impl<'a> TryFrom<&'a str> for LenientBool {
+    type Error = LenientBoolError;
+
+    fn try_from(s: &'a str) -> Result<LenientBool, LenientBoolError> {
+        s.parse()
+    }
+}
+
+/// Parses a string with `FromStr`, for generic code bounded on `TryFrom`.
+#[cfg(feature = "std")]
+impl TryFrom<String> for LenientBool {
+    type Error = LenientBoolError;
+
+    fn try_from(s: String) -> Result<LenientBool, LenientBoolError> {
+        s.parse()
+    }
+}
+
+macro_rules! impl_try_from_int {
+    ($($t:ty),*) => {
+        $(
+            /// Converts `1` to `true` and `0` to `false`. Any other value is an error; use
+            /// `LenientBool::from_int_lenient` to treat every nonzero value as `true`.
+            impl TryFrom<$t> for LenientBool {
+                type Error = LenientBoolError;
+
+                fn try_from(i: $t) -> Result<LenientBool, LenientBoolError> {
+                    match i {
+                        0 => Ok(LenientBool(false)),
+                        1 => Ok(LenientBool(true)),
+                        _ => Err(LenientBoolError::from_display(i)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Writes `true` or `false`, honoring width and alignment.
+impl fmt::Display for LenientBool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl From<LenientBool> for bool {
+    fn from(n: LenientBool) -> bool { n.0 }
+}
+
+impl From<bool> for LenientBool {
+    fn from(b: bool) -> LenientBool { LenientBool(b) }
+}
+
+impl PartialEq<bool> for LenientBool {
+    fn eq(&self, other: &bool) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<LenientBool> for bool {
+    fn eq(&self, other: &LenientBool) -> bool {
+        *self == other.0
+    }
+}
+
+impl Not for LenientBool {
+    type Output = LenientBool;
+    fn not(self) -> LenientBool {
+        LenientBool(!self.0)
+    }
+}
+
+/// Implements a binary operator, and its assigning form, between `LenientBool` and both
+/// `LenientBool` and `bool`, and between `bool` and `LenientBool`.
+macro_rules! impl_bit_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident) => {
+        impl $op for LenientBool {
+            type Output = LenientBool;
+            fn $method(self, rhs: LenientBool) -> LenientBool {
+                LenientBool(self.0.$method(rhs.0))
+            }
+        }
+
+        impl $op<bool> for LenientBool {
+            type Output = LenientBool;
+            fn $method(self, rhs: bool) -> LenientBool {
+                LenientBool(self.0.$method(rhs))
+            }
+        }
+
+        impl $op<LenientBool> for bool {
+            type Output = bool;
+            fn $method(self, rhs: LenientBool) -> bool {
+                self.$method(rhs.0)
+            }
+        }
+
+        impl $assign_op for LenientBool {
+            fn $assign_method(&mut self, rhs: LenientBool) {
+                self.0.$assign_method(rhs.0);
+            }
+        }
+
+        impl $assign_op<bool> for LenientBool {
+            fn $assign_method(&mut self, rhs: bool) {
+                self.0.$assign_method(rhs);
+            }
+        }
+
+        impl $assign_op<LenientBool> for bool {
+            fn $assign_method(&mut self, rhs: LenientBool) {
+                self.$assign_method(rhs.0);
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl AsRef<bool> for LenientBool {
+    fn as_ref(&self) -> &bool {
+        &self.0
+    }
+}
+
+impl AsMut<bool> for LenientBool {
+    fn as_mut(&mut self) -> &mut bool {
+        &mut self.0
+    }
+}
+
+impl Deref for LenientBool {
+    type Target = bool;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for LenientBool {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Lenient boolean parsing as methods on string slices.
+///
+/// ```
+/// use lenient_bool::LenientBoolStr;
+///
+/// assert_eq!("yes".parse_lenient_bool(), Ok(true));
+/// assert!("T".is_truthy());
+/// assert!(!"maybe".is_truthy());
+/// ```
+pub trait LenientBoolStr {
+    /// Parses the string with the `LenientBool` rules.
+    fn parse_lenient_bool(&self) -> Result<bool, LenientBoolError>;
+
+    /// Whether the string parses as `true`. Unrecognized values are not truthy.
+    fn is_truthy(&self) -> bool {
+        self.parse_lenient_bool() == Ok(true)
+    }
+}
+
+impl LenientBoolStr for str {
+    fn parse_lenient_bool(&self) -> Result<bool, LenientBoolError> {
+        self.parse::<LenientBool>().map(bool::from)
+    }
+}
+
+/// Values that can be coerced to a boolean, for ingestion code that receives dynamically typed
+/// values.
+///
+/// Strings are parsed with the `LenientBool` rules, numbers are `false` when zero and `true`
+/// otherwise, and `Option`s delegate to their contents, with `None` an error. With the `json`
+/// feature, `serde_json::Value` is coerced with `LenientBool::try_from_value`.
+///
+/// ```
+/// use lenient_bool::truthy;
+///
+/// assert_eq!(truthy("Yes"), Ok(true));
+/// assert_eq!(truthy(0u8), Ok(false));
+/// assert_eq!(truthy(-2.5), Ok(true));
+/// assert_eq!(truthy(Some(String::from("n"))), Ok(false));
+/// assert!(truthy(None::<i32>).is_err());
+/// assert!(truthy(f64::NAN).is_err());
+/// ```
+pub trait Truthy {
+    fn truthy(&self) -> Result<bool, LenientBoolError>;
+}
+
+/// Coerces `value` to a boolean with its `Truthy` implementation.
+pub fn truthy<T: Truthy>(value: T) -> Result<bool, LenientBoolError> {
+    value.truthy()
+}
+
+impl<T: Truthy + ?Sized> Truthy for &T {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        (**self).truthy()
+    }
+}
+
+impl Truthy for str {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        self.parse_lenient_bool()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Truthy for String {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        self.parse_lenient_bool()
+    }
+}
+
+impl Truthy for char {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        LenientBool::from_char(*self).map(bool::from)
+    }
+}
+
+impl Truthy for bool {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        Ok(*self)
+    }
+}
+
+impl Truthy for LenientBool {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        Ok(self.0)
+    }
+}
+
+macro_rules! impl_truthy_int {
+    ($($t:ty),*) => {
+        $(
+            impl Truthy for $t {
+                fn truthy(&self) -> Result<bool, LenientBoolError> {
+                    Ok(*self != 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_truthy_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_truthy_float {
+    ($($t:ty),*) => {
+        $(
+            /// NaN is an error.
+            impl Truthy for $t {
+                fn truthy(&self) -> Result<bool, LenientBoolError> {
+                    if self.is_nan() {
+                        Err(LenientBoolError::new("NaN"))
+                    } else {
+                        Ok(*self != 0.0)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_truthy_float!(f32, f64);
+
+impl<T: Truthy> Truthy for Option<T> {
+    fn truthy(&self) -> Result<bool, LenientBoolError> {
+        match self {
+            Some(value) => value.truthy(),
+            None => Err(LenientBoolError::new("null")),
+        }
+    }
+}
+
+/// A policy for parsing booleans, so that library code can be generic over it and take
+/// parsers from its callers.
+///
+/// `Strictness` implements it with `LenientBool::from_str_with`, so `Strictness::default()`
+/// is the usual `LenientBool` rules, and `LenientBoolParser` and `CompiledParser` implement it
+/// with their own rules.
+///
+/// ```
+/// use lenient_bool::{BoolParser, LenientBoolParser, Strictness};
+///
+/// fn parse_column(parser: &impl BoolParser, column: &[&str]) -> Vec<Option<bool>> {
+///     column.iter().map(|s| parser.parse(s).ok()).collect()
+/// }
+///
+/// assert_eq!(parse_column(&Strictness::default(), &["yes", "on"]), [Some(true), None]);
+/// assert_eq!(parse_column(&LenientBoolParser::python(), &["yes", "on"]), [Some(true), Some(true)]);
+/// ```
+pub trait BoolParser {
+    fn parse(&self, s: &str) -> Result<bool, LenientBoolError>;
+}
+
+impl BoolParser for Strictness {
+    fn parse(&self, s: &str) -> Result<bool, LenientBoolError> {
+        LenientBool::from_str_with(s, *self).map(bool::from)
+    }
+}
+
+impl<P: BoolParser + ?Sized> BoolParser for &P {
+    fn parse(&self, s: &str) -> Result<bool, LenientBoolError> {
+        (**self).parse(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: BoolParser + ?Sized> BoolParser for Box<P> {
+    fn parse(&self, s: &str) -> Result<bool, LenientBoolError> {
+        (**self).parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::*;
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_f() {
+        assert_eq!("f".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_f_upper() {
+        assert_eq!("F".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn parse_false() {
+        assert_eq!("false".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn parse_false_cap() {
+        assert_eq!("False".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn parse_false_upper() {
+        assert_eq!("FALSE".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_n() {
+        assert_eq!("n".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_n_upper() {
+        assert_eq!("N".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_no() {
+        assert_eq!("no".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_no_cap() {
+        assert_eq!("No".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_no_upper() {
+        assert_eq!("NO".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-numeric")]
+    fn parse_0() {
+        assert_eq!("0".parse::<LenientBool>(), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_t() {
+        assert_eq!("t".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_t_upper() {
+        assert_eq!("T".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn parse_true() {
+        assert_eq!("true".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn parse_true_cap() {
+        assert_eq!("True".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn parse_true_upper() {
+        assert_eq!("TRUE".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_y() {
+        assert_eq!("y".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn parse_y_upper() {
+        assert_eq!("Y".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_yes() {
+        assert_eq!("yes".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_yes_cap() {
+        assert_eq!("Yes".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_yes_upper() {
+        assert_eq!("YES".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-numeric")]
+    fn parse_1() {
+        assert_eq!("1".parse::<LenientBool>(), Ok(LenientBool(true)));
+    }
+
+    mod errors {
+        use super::*;
+
+        #[test]
+        fn parse_empty_err() {
+            assert_eq!("".parse::<LenientBool>(), Err(LenientBoolError::new("")));
+        }
+
+        #[test]
+        fn parse_bad_input_err() {
+            assert_eq!("abc".parse::<LenientBool>(), Err(LenientBoolError::new("abc")));
+        }
+
+        #[test]
+        fn error_display() {
+            let err = "abc".parse::<LenientBool>().unwrap_err();
+            assert_eq!(err.to_string(), "invalid boolean value \"abc\"");
+            assert_eq!(format!("{:?}", err), "LenientBoolError { input: \"abc\", truncated: false }");
+        }
+
+        #[test]
+        fn error_truncated() {
+            let input = "é".repeat(40);
+            let err = input.parse::<LenientBool>().unwrap_err();
+            assert!(err.is_truncated());
+            assert_eq!(err.input(), "é".repeat(32));
+            assert!(err.to_string().ends_with("\" (truncated)"));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-yes-no")]
+        fn error_suggestion() {
+            for (input, suggestion) in [
+                ("treu", Some("true")),
+                ("FLASE", Some("false")),
+                ("yse", Some("yes")),
+                ("noo", Some("no")),
+                ("fals", Some("false")),
+                ("maybe", None),
+                ("2", None),
+                ("", None),
+            ] {
+                let err = LenientBoolError::new(input);
+                assert_eq!(err.suggestion(), suggestion, "{:?}", input);
+            }
+            assert_eq!(LenientBoolError::new("yes").suggestion(), None);
+        }
+
+        #[test]
+        fn error_display_alternate() {
+            let err = "abc".parse::<LenientBool>().unwrap_err();
+            let tokens: Vec<&str> = TRUE_TOKENS.iter().chain(FALSE_TOKENS).copied().collect();
+            assert_eq!(
+                format!("{:#}", err),
+                format!("invalid boolean value \"abc\". Expected one of: {}", tokens.join(", "))
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn error_source_chain() {
+            let err: Box<dyn std::error::Error> = Box::new("abc".parse::<LenientBool>().unwrap_err());
+            assert_eq!(err.to_string(), "invalid boolean value \"abc\"");
+        }
+    }
+
+    mod tokens {
+        use super::*;
+
+        #[test]
+        fn all_tokens_parse() {
+            for token in Token::ALL.iter().filter(|t| t.is_enabled()) {
+                assert_eq!(token.text().parse::<LenientBool>(), Ok(LenientBool(token.value())));
+            }
+        }
+
+        #[test]
+        #[cfg(not(feature = "tokens-numeric"))]
+        fn numeric_disabled() {
+            assert!("1".parse::<LenientBool>().is_err());
+            assert!(!Token::Zero.is_enabled());
+            assert!(LenientBool::falsy_tokens().all(|t| t != "0"));
+        }
+
+        #[test]
+        fn truthy_and_falsy_tokens() {
+            assert!(LenientBool::truthy_tokens().all(|t| t.parse::<LenientBool>() == Ok(LenientBool(true))));
+            assert!(LenientBool::falsy_tokens().all(|t| t.parse::<LenientBool>() == Ok(LenientBool(false))));
+        }
+
+        #[test]
+        fn token_display() {
+            assert_eq!(Token::Yes.to_string(), "yes");
+        }
+
+        #[test]
+        fn ascii_tokens_are_short_and_lowercase() {
+            let packs = EXTENDED_PACKS.iter().flat_map(|p| p.true_tokens().iter().chain(p.false_tokens()));
+            for token in TRUE_TOKENS.iter().chain(FALSE_TOKENS).chain(packs).filter(|t| t.is_ascii()) {
+                assert!(token.len() <= MAX_ASCII_TOKEN_LEN, "{:?}", token);
+                assert_eq!(token.to_ascii_lowercase(), *token);
+            }
+        }
+
+        #[test]
+        fn long_ascii_input_err() {
+            assert!("yes".repeat(MAX_ASCII_TOKEN_LEN).parse::<LenientBool>().is_err());
+        }
+    }
+
+    mod strictness {
+        use super::*;
+
+        #[test]
+        fn strict() {
+            assert_eq!(LenientBool::from_str_with("False", Strictness::Strict), Ok(LenientBool(false)));
+            for s in ["t", "1", "no", "on"] {
+                assert!(LenientBool::from_str_with(s, Strictness::Strict).is_err(), "{}", s);
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-single-letter")]
+        fn standard() {
+            assert_eq!(LenientBool::from_str_with("N", Strictness::Standard), Ok(LenientBool(false)));
+            assert!(LenientBool::from_str_with("on", Strictness::Standard).is_err());
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-numeric")]
+        fn extended() {
+            assert_eq!(LenientBool::from_str_with("Enabled", Strictness::Extended), Ok(LenientBool(true)));
+            assert_eq!(LenientBool::from_str_with("0", Strictness::Extended), Ok(LenientBool(false)));
+            assert!(LenientBool::from_str_with("maybe", Strictness::Extended).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_trimmed() {
+        assert_eq!(LenientBool::from_str_trimmed("\tno \n"), Ok(LenientBool(false)));
+        assert!(LenientBool::from_str_trimmed(" ").is_err());
+    }
+
+    mod bytes {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "tokens-single-letter")]
+        fn from_bytes() {
+            assert_eq!(LenientBool::from_bytes(b"TRUE"), Ok(LenientBool(true)));
+            assert_eq!(LenientBool::try_from(&b"n"[..]), Ok(LenientBool(false)));
+        }
+
+        #[test]
+        fn from_bytes_invalid_utf8_err() {
+            let err = LenientBool::from_bytes(b"ab\xffc").unwrap_err();
+            assert_eq!(err.input(), "ab");
+            assert!(err.is_truncated());
+        }
+
+        #[test]
+        fn from_bytes_long_input_err() {
+            let err = LenientBool::from_bytes(&[b'x'; 100]).unwrap_err();
+            assert_eq!(err.input().len(), LenientBoolError::MAX_INPUT_LEN);
+            assert!(err.is_truncated());
+        }
+
+        #[test]
+        #[cfg(unix)]
+        #[cfg(feature = "std")]
+        fn from_os_str_non_utf8_err() {
+            use std::os::unix::ffi::OsStrExt;
+
+            let s = std::ffi::OsStr::from_bytes(b"y\xff");
+            assert_eq!(LenientBool::from_os_str(s).unwrap_err().input(), "y");
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn from_bytes_uses_current_parser() {
+            let result = LenientBool::with_parser(LenientBoolParser::go(), || LenientBool::from_bytes(b"yes"));
+            assert!(result.is_err());
+        }
+    }
+
+    mod unicode {
+        use super::*;
+
+        #[test]
+        fn turkish_i() {
+            assert!(eq_ignore_case("NEİN", "nein"));
+            assert!(eq_ignore_case("neın", "nein"));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-yes-no")]
+        fn long_s() {
+            assert_eq!("YEſ".parse::<LenientBool>(), Ok(LenientBool(true)));
+        }
+
+        #[test]
+        fn non_ascii_lookalike_err() {
+            // A Cyrillic `е` isn't folded to a Latin `e`.
+            assert!("y\u{435}s".parse::<LenientBool>().is_err());
+        }
+    }
+
+    #[cfg(feature = "nfkc")]
+    mod nfkc {
+        use super::*;
+
+        #[test]
+        fn compatibility_forms() {
+            // Full-width letters, a circled digit, and a superscript digit.
+            for (s, value) in [("ｙｅｓ", true), ("①", true), ("⁰", false), ("ＦＡＬＳＥ", false)] {
+                assert_eq!(s.parse::<LenientBool>(), Ok(LenientBool(value)), "{}", s);
+            }
+        }
+
+        #[test]
+        fn bytes_are_normalized() {
+            assert_eq!(LenientBool::from_bytes("ｙ".as_bytes()), Ok(LenientBool(true)));
+        }
+
+        #[test]
+        fn error_keeps_original_input() {
+            assert_eq!("ｍａｙｂｅ".parse::<LenientBool>().unwrap_err().input(), "ｍａｙｂｅ");
+        }
+    }
+
+    #[cfg(feature = "fullwidth")]
+    mod fullwidth {
+        use super::*;
+
+        #[test]
+        fn fullwidth_tokens() {
+            let cases = [("１", true), ("０", false), ("ｔｒｕｅ", true), ("Ｙ", true), ("ｎＯ", false)];
+            for (s, value) in cases {
+                assert_eq!(s.parse::<LenientBool>(), Ok(LenientBool(value)), "{}", s);
+            }
+        }
+
+        #[test]
+        fn fullwidth_mixed() {
+            assert_eq!("tｒue".parse::<LenientBool>(), Ok(LenientBool(true)));
+            assert!("ｍａｙｂｅ".parse::<LenientBool>().is_err());
+        }
+    }
+
+    mod str_ext {
+        use super::*;
+
+        #[test]
+        fn parse_lenient_bool() {
+            #[cfg(feature = "tokens-single-letter")]
+            assert_eq!("N".parse_lenient_bool(), Ok(false));
+            assert_eq!("abc".parse_lenient_bool(), Err(LenientBoolError::new("abc")));
+        }
+
+        #[test]
+        fn is_truthy() {
+            #[cfg(feature = "tokens-numeric")]
+            assert!(String::from("1").is_truthy());
+            assert!(!"false".is_truthy());
+            assert!(!"".is_truthy());
+        }
+    }
+
+    mod parse_or {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "tokens-numeric")]
+        fn parse_or() {
+            assert!(LenientBool::parse_or("1", false));
+            assert!(!LenientBool::parse_or("", false));
+            assert!(LenientBool::parse_or("", true));
+        }
+
+        #[test]
+        fn parse_or_default() {
+            assert!(!LenientBool::parse_or_default("f"));
+            assert!(!LenientBool::parse_or_default("abc"));
+        }
+    }
+
+    mod const_parse {
+        use super::*;
+
+        #[cfg(feature = "tokens-single-letter")]
+        const OFF: Option<LenientBool> = LenientBool::const_parse("N");
+
+        #[test]
+        #[cfg(feature = "tokens-single-letter")]
+        fn const_item() {
+            assert_eq!(OFF, Some(LenientBool(false)));
+        }
+
+        #[test]
+        fn macro_expands() {
+            assert!(lenient_bool!("TRUE"));
+            assert!(!lenient_bool!("False"));
+        }
+
+        #[test]
+        fn agrees_with_from_str() {
+            for s in ["TRUE", "t", "yEs", "1", "false", "F", "no", "0", "", "yes ", "on", "2"] {
+                assert_eq!(LenientBool::const_parse(s), s.parse::<LenientBool>().ok(), "{:?}", s);
+            }
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn display() {
+            assert_eq!(LenientBool(true).to_string(), "true");
+            assert_eq!(format!("[{:>6}]", LenientBool(false)), "[ false]");
+        }
+
+        #[test]
+        fn as_str_matches_display() {
+            for value in [LenientBool(true), LenientBool(false)] {
+                assert_eq!(value.as_str(), value.to_string());
+            }
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "tokens-numeric",
+            feature = "tokens-single-letter",
+            feature = "tokens-yes-no"
+        ))]
+        fn styles_round_trip() {
+            let styles = [
+                BoolStyle::TrueFalse,
+                BoolStyle::YesNo,
+                BoolStyle::OnOff,
+                BoolStyle::Numeric,
+                BoolStyle::TF,
+                BoolStyle::YN,
+            ];
+            for style in styles {
+                for value in [true, false] {
+                    let text = LenientBool(value).format_as(style);
+                    assert_eq!(
+                        LenientBool::from_str_with(text, Strictness::Extended),
+                        Ok(LenientBool(value)),
+                        "{}",
+                        text
+                    );
+                }
+            }
+        }
+    }
+
+    mod heuristic {
+        use super::*;
+
+        #[test]
+        fn heuristic_true() {
+            for s in ["yes", "TRUE", "1", "+1", "  y"] {
+                assert!(LenientBool::parse_heuristic(s), "{}", s);
+            }
+        }
+
+        #[test]
+        fn heuristic_false() {
+            for s in ["no", "0", "-", "", "  ", "off", "on"] {
+                assert!(!LenientBool::parse_heuristic(s), "{}", s);
+            }
+        }
+    }
+
+    mod truthy {
+        use super::*;
+
+        #[test]
+        #[cfg(all(
+            feature = "tokens-numeric",
+            feature = "tokens-single-letter",
+            feature = "tokens-yes-no"
+        ))]
+        fn strings() {
+            assert_eq!(truthy("T"), Ok(true));
+            #[cfg(feature = "std")]
+            assert_eq!(truthy(String::from("no")), Ok(false));
+            assert_eq!(truthy('1'), Ok(true));
+            assert_eq!(truthy("abc"), Err(LenientBoolError::new("abc")));
+        }
+
+        #[test]
+        fn numbers() {
+            assert_eq!(truthy(0i64), Ok(false));
+            assert_eq!(truthy(7usize), Ok(true));
+            assert_eq!(truthy(-1i8), Ok(true));
+            assert_eq!(truthy(0.0f32), Ok(false));
+            assert_eq!(truthy(-0.0), Ok(false));
+            assert_eq!(truthy(f32::NAN), Err(LenientBoolError::new("NaN")));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-single-letter")]
+        fn options() {
+            assert_eq!(truthy(Some(true)), Ok(true));
+            assert_eq!(truthy(Some("y")), Ok(true));
+            assert_eq!(truthy(None::<&str>), Err(LenientBoolError::new("null")));
+            assert_eq!(truthy(Some(Some(0))), Ok(false));
+        }
+    }
+
+    mod bool_parser {
+        use super::*;
+
+        fn parse_with<P: BoolParser>(parser: P, s: &str) -> Result<bool, LenientBoolError> {
+            parser.parse(s)
+        }
+
+        #[test]
+        fn strictness() {
+            #[cfg(feature = "tokens-single-letter")]
+            assert_eq!(parse_with(Strictness::default(), "Y"), Ok(true));
+            #[cfg(feature = "tokens-single-letter")]
+            assert!(parse_with(Strictness::Strict, "Y").is_err());
+            assert_eq!(parse_with::<&Strictness>(&Strictness::Extended, "off"), Ok(false));
+        }
+
+        #[test]
+        #[cfg(all(feature = "std", feature = "tokens-yes-no"))]
+        fn boxed() {
+            let parsers: Vec<Box<dyn BoolParser>> =
+                vec![Box::new(Strictness::Standard), Box::new(LenientBoolParser::go())];
+            let results: Vec<_> = parsers.iter().map(|p| p.parse("yes").ok()).collect();
+            assert_eq!(results, [Some(true), None]);
+        }
+    }
+
+    mod outcome {
+        use super::*;
+
+        #[test]
+        #[cfg(all(
+            feature = "tokens-numeric",
+            feature = "tokens-single-letter",
+            feature = "tokens-yes-no"
+        ))]
+        fn parse_outcome() {
+            for (s, matched, class) in [
+                ("TRUE", "true", TokenClass::Word),
+                ("No", "no", TokenClass::Word),
+                ("f", "f", TokenClass::SingleLetter),
+                ("1", "1", TokenClass::Numeric),
+            ] {
+                let outcome = LenientBool::parse_outcome(s).unwrap();
+                assert_eq!((outcome.matched, outcome.class), (matched, class), "{:?}", s);
+                assert_eq!(Ok(LenientBool(outcome.value)), s.parse(), "{:?}", s);
+            }
+        }
+
+        #[test]
+        fn parse_outcome_bad_input_err() {
+            assert_eq!(LenientBool::parse_outcome("abc"), Err(LenientBoolError::new("abc")));
+        }
+
+        #[test]
+        #[cfg(feature = "locale-fr")]
+        fn parse_outcome_localized() {
+            let outcome = LenientBool::parse_outcome("OUI").unwrap();
+            assert_eq!((outcome.matched, outcome.class), ("oui", TokenClass::Localized));
+        }
+
+        #[test]
+        fn every_token_has_an_outcome() {
+            for token in Token::ALL.iter().filter(|t| t.is_enabled()) {
+                let outcome = LenientBool::parse_outcome(token.text()).unwrap();
+                assert_eq!((outcome.value, outcome.class), (token.value(), token.class()));
+            }
+        }
+    }
+
+    mod prefix {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "tokens-yes-no")]
+        fn parse_prefix() {
+            assert_eq!(LenientBool::parse_prefix("true"), Some((LenientBool(true), "")));
+            assert_eq!(LenientBool::parse_prefix("No, thanks"), Some((LenientBool(false), ", thanks")));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-yes-no")]
+        fn parse_prefix_longest_token() {
+            assert_eq!(LenientBool::parse_prefix("yes!"), Some((LenientBool(true), "!")));
+        }
+
+        #[test]
+        fn parse_prefix_needs_boundary() {
+            for s in ["true_value", "ya", "10", " yes", ""] {
+                assert_eq!(LenientBool::parse_prefix(s), None, "{:?}", s);
+            }
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "tokens-numeric",
+            feature = "tokens-single-letter",
+            feature = "tokens-yes-no"
+        ))]
+        fn extract_first_token() {
+            assert_eq!(extract("yes"), Some((true, 0..3)));
+            assert_eq!(extract("status=0, retry=true"), Some((false, 7..8)));
+            assert_eq!(extract("¿Sí? no"), Some((false, 7..9)));
+            assert_eq!(extract("(T)"), Some((true, 1..2)));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-single-letter")]
+        fn extract_needs_word_boundaries() {
+            for s in ["notable", "eyes", "yesterday", "a_yes", "10 items", "truest", ""] {
+                assert_eq!(extract(s), None, "{:?}", s);
+            }
+            assert_eq!(extract("eyes: Y"), Some((true, 6..7)));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod list {
+        use super::*;
+
+        #[test]
+        #[cfg(all(feature = "tokens-numeric", feature = "tokens-yes-no"))]
+        fn parse_list() {
+            assert_eq!(LenientBool::parse_list("yes, no ,1", ','), Ok(vec![true, false, true]));
+        }
+
+        #[test]
+        fn parse_list_empty() {
+            assert_eq!(LenientBool::parse_list("  ", ','), Ok(vec![]));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-yes-no")]
+        fn parse_list_trailing_delimiter() {
+            assert_eq!(LenientBool::parse_list("yes, no, ", ','), Ok(vec![true, false]));
+            assert_eq!(LenientBool::parse_list(",", ','), Ok(vec![]));
+            assert!(LenientBool::parse_list("yes,,", ',').is_err());
+        }
+
+        #[test]
+        #[cfg(all(feature = "tokens-numeric", feature = "tokens-single-letter"))]
+        fn parse_list_values() {
+            assert_eq!(
+                super::parse_list("t ;0", ';'),
+                Ok(vec![LenientBool(true), LenientBool(false)])
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-single-letter")]
+        fn parse_list_other_delimiter() {
+            assert_eq!(LenientBool::parse_list("t;F", ';'), Ok(vec![true, false]));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-yes-no")]
+        fn parse_list_errors() {
+            let err = LenientBool::parse_list("abc, yes,, x ", ',').unwrap_err();
+            assert_eq!(err.items, vec![
+                ListItemError { index: 0, offset: 0, value: "abc".to_owned() },
+                ListItemError { index: 2, offset: 9, value: "".to_owned() },
+                ListItemError { index: 3, offset: 11, value: "x".to_owned() },
+            ]);
+            assert_eq!(
+                err.to_string(),
+                "invalid boolean value \"abc\" in item 0 at offset 0; \
+                 invalid boolean value \"\" in item 2 at offset 9; \
+                 invalid boolean value \"x\" in item 3 at offset 11"
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod batch {
+        use super::*;
+
+        #[test]
+        #[cfg(all(
+            feature = "tokens-numeric",
+            feature = "tokens-single-letter",
+            feature = "tokens-yes-no"
+        ))]
+        fn parse_all_ok() {
+            assert_eq!(parse_all(&["Yes", "n", "1"]), Ok(vec![true, false, true]));
+            assert_eq!(parse_all::<&str>(&[]), Ok(vec![]));
+        }
+
+        #[test]
+        #[cfg(feature = "tokens-single-letter")]
+        fn parse_all_owned() {
+            let values = vec![String::from("true"), String::from("F")];
+            assert_eq!(parse_all(&values), Ok(vec![true, false]));
+        }
+
+        #[test]
+        fn parse_all_errors() {
+            let err = parse_all(&["abc", "true", " no"]).unwrap_err();
+            assert_eq!(err.items, vec![
+                BatchItemError { index: 0, value: "abc".to_owned() },
+                BatchItemError { index: 2, value: " no".to_owned() },
+            ]);
+            assert_eq!(
+                err.to_string(),
+                "invalid boolean value \"abc\" at index 0; invalid boolean value \" no\" at index 2"
+            );
+        }
+    }
+
+    mod conversion {
+        use super::*;
+
+        #[test]
+        #[allow(clippy::bool_assert_comparison)]
+        fn from() {
+            let lb = "true".parse::<LenientBool>();
+            assert!(lb.is_ok());
+            let lb = lb.unwrap();
+
+            // The preferred way.
+            let b : bool = lb.into();
+            assert_eq!(b, true);
+
+            let lb = "false".parse::<LenientBool>();
+            assert!(lb.is_ok());
+            let lb = lb.unwrap();
+
+            // The preferred way.
+            let b : bool = lb.into();
+            assert_eq!(b, false);
+        }
+
+        #[test]
+        #[allow(clippy::bool_assert_comparison)]
+        fn deref() {
+            let lb = "true".parse::<LenientBool>();
+            assert!(lb.is_ok());
+            let lb = lb.unwrap();
+
+            assert_eq!(*lb, true);
+
+            let lb = "false".parse::<LenientBool>();
+            assert!(lb.is_ok());
+            let lb = lb.unwrap();
+
+            assert_eq!(*lb, false);
+        }
+
+        #[test]
+        #[allow(clippy::bool_comparison)]
+        #[cfg(feature = "tokens-yes-no")]
+        fn eq_bool() {
+            let parsed = "yes".parse::<LenientBool>().unwrap();
+            assert!(parsed == true);
+            assert!(false != parsed);
+            assert_eq!(LenientBool(false), false);
+        }
+
+        #[test]
+        fn operators() {
+            let (t, f) = (LenientBool(true), LenientBool(false));
+            assert_eq!(!t, f);
+            assert_eq!(t & f, f);
+            assert_eq!(t | false, t);
+            assert_eq!(t ^ t, f);
+            assert!(true & t);
+
+            let mut flag = f;
+            flag |= t;
+            assert_eq!(flag, t);
+            flag ^= true;
+            assert_eq!(flag, f);
+
+            let mut mask = true;
+            mask &= f;
+            assert!(!mask);
+        }
+
+        #[test]
+        #[cfg(all(feature = "tokens-numeric", feature = "tokens-single-letter"))]
+        fn from_char() {
+            for (c, value) in [('t', true), ('F', false), ('y', true), ('N', false), ('1', true), ('0', false)] {
+                assert_eq!(LenientBool::try_from(c), Ok(LenientBool(value)), "{}", c);
+            }
+            assert_eq!(LenientBool::from_char('2'), Err(LenientBoolError::new("2")));
+        }
+
+        #[test]
+        #[cfg(all(feature = "tokens-single-letter", feature = "tokens-yes-no"))]
+        fn try_from_str() {
+            assert_eq!(LenientBool::try_from("Y"), Ok(LenientBool(true)));
+            #[cfg(feature = "std")]
+            assert_eq!(LenientBool::try_from(String::from("no")), Ok(LenientBool(false)));
+            assert_eq!(LenientBool::try_from("abc"), Err(LenientBoolError::new("abc")));
+        }
+
+        #[test]
+        fn try_from_int() {
+            assert_eq!(LenientBool::try_from(1u8), Ok(LenientBool(true)));
+            assert_eq!(LenientBool::try_from(0i64), Ok(LenientBool(false)));
+            assert_eq!(LenientBool::try_from(2u8), Err(LenientBoolError::new("2")));
+            assert_eq!(LenientBool::try_from(-1i64), Err(LenientBoolError::new("-1")));
+            assert_eq!(LenientBool::try_from(u128::MAX).unwrap_err().input(), u128::MAX.to_string());
+        }
+
+        #[test]
+        fn from_int_lenient() {
+            assert_eq!(LenientBool::from_int_lenient(i64::MIN), LenientBool(true));
+            assert_eq!(LenientBool::from_int_lenient(0), LenientBool(false));
         }
     }
 
@@ -0,0 +1,107 @@
+//! A `minijinja` filter for lenient booleans.
+//!
+//! The `lenient_bool` filter converts booleans, numbers, and strings with the `LenientBool`
+//! rules, so templates can branch on user-provided values without caring how they were
+//! spelled. Numbers are true when nonzero, and undefined and `none` values are false.
+//!
+//! Values that can't be converted are an error by default. Pass `default` to use a fallback
+//! instead, so a bad value can't break rendering.
+//!
+//! ```
+//! use minijinja::{context, Environment};
+//!
+//! let mut env = Environment::new();
+//! env.add_filter("lenient_bool", lenient_bool::minijinja::lenient_bool);
+//!
+//! let template = env
+//!     .template_from_str("{% if beta | lenient_bool %}beta{% else %}stable{% endif %}")
+//!     .unwrap();
+//! assert_eq!(template.render(context! { beta => "Yes" }).unwrap(), "beta");
+//! assert_eq!(template.render(context! {}).unwrap(), "stable");
+//! assert!(template.render(context! { beta => "maybe" }).is_err());
+//!
+//! let template = env
+//!     .template_from_str("{{ beta | lenient_bool(default=false) }}")
+//!     .unwrap();
+//! assert_eq!(template.render(context! { beta => "maybe" }).unwrap(), "False");
+//! ```
+
+use ::minijinja::value::{Kwargs, Value, ValueKind};
+use ::minijinja::{Error, ErrorKind};
+
+use super::LenientBool;
+
+fn to_bool(value: &Value) -> Option<bool> {
+    match value.kind() {
+        ValueKind::Undefined | ValueKind::None => Some(false),
+        ValueKind::Bool => Some(value.is_true()),
+        ValueKind::Number => f64::try_from(value.clone())
+            .ok()
+            .filter(|n| !n.is_nan())
+            .map(|n| n != 0.0),
+        ValueKind::String => value
+            .as_str()
+            .and_then(|s| s.parse::<LenientBool>().ok())
+            .map(bool::from),
+        _ => None,
+    }
+}
+
+/// The `lenient_bool` filter. Register it with `Environment::add_filter`.
+pub fn lenient_bool(value: &Value, kwargs: Kwargs) -> Result<bool, Error> {
+    let default: Option<bool> = kwargs.get("default")?;
+    kwargs.assert_all_used()?;
+    to_bool(value).or(default).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("invalid boolean value {}", value),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use ::minijinja::{context, Environment};
+
+    use super::*;
+
+    fn render(source: &str, value: Value) -> Result<String, Error> {
+        let mut env = Environment::new();
+        env.add_filter("lenient_bool", lenient_bool);
+        env.render_str(source, context! { value })
+    }
+
+    #[test]
+    fn filter_string() {
+        let out = render("{{ value | lenient_bool }}", Value::from("N")).unwrap();
+        assert_eq!(out, "False");
+    }
+
+    #[test]
+    fn filter_number() {
+        let out = render("{{ value | lenient_bool }}", Value::from(3)).unwrap();
+        assert_eq!(out, "True");
+    }
+
+    #[test]
+    fn filter_undefined() {
+        let out = render("{{ value | lenient_bool }}", Value::UNDEFINED).unwrap();
+        assert_eq!(out, "False");
+    }
+
+    #[test]
+    fn filter_bad_input_err() {
+        let err = render("{{ value | lenient_bool }}", Value::from("abc")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+    }
+
+    #[test]
+    fn filter_default() {
+        let out = render(
+            "{{ value | lenient_bool(default=true) }}",
+            Value::from("abc"),
+        )
+        .unwrap();
+        assert_eq!(out, "True");
+    }
+}
@@ -0,0 +1,115 @@
+use ::mlua::{Error, FromLua, IntoLua, Lua, Result, Value};
+
+use super::LenientBool;
+
+fn conversion_error(value: &Value, message: String) -> Error {
+    Error::FromLuaConversionError {
+        from: value.type_name(),
+        to: "LenientBool".to_owned(),
+        message: Some(message),
+    }
+}
+
+/// Converts Lua booleans, numbers, and strings to a `LenientBool`.
+///
+/// Numbers are true when nonzero, and strings are parsed with the `LenientBool` rules. `nil`
+/// is rejected, so use `Option<LenientBool>` for values that may be missing.
+///
+/// ```
+/// use lenient_bool::LenientBool;
+/// use mlua::Lua;
+///
+/// let lua = Lua::new();
+/// let settings: mlua::Table = lua
+///     .load(r#"{ verbose = "yes", color = 0, debug = true }"#)
+///     .eval()
+///     .unwrap();
+///
+/// assert_eq!(settings.get::<LenientBool>("verbose").unwrap(), LenientBool(true));
+/// assert_eq!(settings.get::<LenientBool>("color").unwrap(), LenientBool(false));
+/// assert_eq!(settings.get::<Option<LenientBool>>("missing").unwrap(), None);
+/// ```
+impl FromLua for LenientBool {
+    fn from_lua(value: Value, _lua: &Lua) -> Result<LenientBool> {
+        match &value {
+            Value::Boolean(b) => Ok(LenientBool(*b)),
+            Value::Integer(i) => Ok(LenientBool(*i != 0)),
+            Value::Number(n) if !n.is_nan() => Ok(LenientBool(*n != 0.0)),
+            Value::String(s) => s
+                .to_str()
+                .ok()
+                .and_then(|s| s.parse::<LenientBool>().ok())
+                .ok_or_else(|| {
+                    let s = s.to_string_lossy();
+                    conversion_error(&value, format!("invalid boolean value {:?}", s))
+                }),
+            _ => Err(conversion_error(
+                &value,
+                "expected a boolean, number, or string".to_owned(),
+            )),
+        }
+    }
+}
+
+/// Converts a `LenientBool` to a Lua boolean.
+impl IntoLua for LenientBool {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn eval(lua: &Lua, code: &str) -> Result<LenientBool> {
+        lua.load(code).eval()
+    }
+
+    #[test]
+    fn lua_boolean() {
+        let lua = Lua::new();
+        assert_eq!(eval(&lua, "false").unwrap(), LenientBool(false));
+    }
+
+    #[test]
+    fn lua_integer() {
+        let lua = Lua::new();
+        assert_eq!(eval(&lua, "2").unwrap(), LenientBool(true));
+    }
+
+    #[test]
+    fn lua_number() {
+        let lua = Lua::new();
+        assert_eq!(eval(&lua, "0.0").unwrap(), LenientBool(false));
+    }
+
+    #[test]
+    fn lua_string() {
+        let lua = Lua::new();
+        assert_eq!(eval(&lua, "'Y'").unwrap(), LenientBool(true));
+    }
+
+    #[test]
+    fn lua_bad_string_err() {
+        let lua = Lua::new();
+        let err = eval(&lua, "'abc'").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error converting Lua string to LenientBool (invalid boolean value \"abc\")"
+        );
+    }
+
+    #[test]
+    fn lua_nil_err() {
+        let lua = Lua::new();
+        assert!(eval(&lua, "nil").is_err());
+    }
+
+    #[test]
+    fn into_lua() {
+        let lua = Lua::new();
+        lua.globals().set("flag", LenientBool(true)).unwrap();
+        assert!(lua.load("flag == true").eval::<bool>().unwrap());
+    }
+}
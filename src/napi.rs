@@ -0,0 +1,81 @@
+//! Node.js bindings built with napi-rs.
+//!
+//! Build the library as a `cdylib` and load it as a Node addon:
+//!
+//! ```text
+//! cargo rustc --release --features napi --crate-type cdylib
+//! cp target/release/liblenient_bool.so lenient_bool.node
+//! ```
+//!
+//! The exported functions are:
+//!
+//! ```text
+//! parseLenientBool(input: string): boolean | null
+//! parseLenientBools(inputs: string[]): (boolean | null)[]
+//! lenientBoolTrueTokens(): string[]
+//! lenientBoolFalseTokens(): string[]
+//! ```
+//!
+//! `parseLenientBools` parses a whole array in one call, which avoids crossing the
+//! JavaScript/native boundary once per value when ingesting large amounts of data.
+
+use ::napi_derive::napi;
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+/// Parses `input` with the `LenientBool` rules, returning `None` if it isn't recognized.
+#[napi(js_name = "parseLenientBool")]
+pub fn parse_lenient_bool(input: String) -> Option<bool> {
+    input.parse::<LenientBool>().ok().map(bool::from)
+}
+
+/// Parses each of `inputs` with the `LenientBool` rules. Unrecognized values become `None`.
+#[napi(js_name = "parseLenientBools")]
+pub fn parse_lenient_bools(inputs: Vec<String>) -> Vec<Option<bool>> {
+    inputs
+        .iter()
+        .map(|s| s.parse::<LenientBool>().ok().map(bool::from))
+        .collect()
+}
+
+/// The spellings that parse as `true`.
+#[napi(js_name = "lenientBoolTrueTokens")]
+pub fn true_tokens() -> Vec<String> {
+    TRUE_TOKENS.iter().map(|t| t.to_string()).collect()
+}
+
+/// The spellings that parse as `false`.
+#[napi(js_name = "lenientBoolFalseTokens")]
+pub fn false_tokens() -> Vec<String> {
+    FALSE_TOKENS.iter().map(|t| t.to_string()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(parse_lenient_bool("yes".to_owned()), Some(true));
+    }
+
+    #[test]
+    fn parse_bad_input() {
+        assert_eq!(parse_lenient_bool("abc".to_owned()), None);
+    }
+
+    #[test]
+    fn parse_batch() {
+        let inputs = vec!["Y".to_owned(), "abc".to_owned(), "0".to_owned()];
+        assert_eq!(
+            parse_lenient_bools(inputs),
+            vec![Some(true), None, Some(false)]
+        );
+    }
+
+    #[test]
+    fn tokens() {
+        assert!(true_tokens().contains(&"yes".to_owned()));
+        assert!(false_tokens().contains(&"no".to_owned()));
+    }
+}
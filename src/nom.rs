@@ -0,0 +1,114 @@
+//! A `nom` combinator for lenient booleans.
+//!
+//! ```
+//! use lenient_bool::nom::lenient_bool;
+//! use nom::bytes::complete::tag;
+//! use nom::sequence::preceded;
+//!
+//! let (rest, value) = preceded(tag("debug="), lenient_bool)("debug=Yes; level=2").unwrap();
+//! assert!(value);
+//! assert_eq!(rest, "; level=2");
+//! ```
+//!
+//! `lenient_bool` reports errors as `nom::error::Error`. Grammars with their own error type,
+//! such as one that collects context for an expression language, can use
+//! `lenient_bool_token` instead.
+
+use ::nom::error::{ErrorKind, ParseError};
+use ::nom::{Err, IResult};
+
+use super::parse_prefix;
+
+/// Consumes a boolean token from the start of `input`, returning the rest of the input and
+/// the parsed value.
+///
+/// The longest matching token is used, and it must not be followed by another word
+/// character, so `yesterday` is rejected rather than parsed as `yes`. Failures are reported
+/// as `ErrorKind::Tag`.
+pub fn lenient_bool(input: &str) -> IResult<&str, bool> {
+    lenient_bool_token(input)
+}
+
+/// `lenient_bool`, with failures reported as any `nom` error type.
+///
+/// ```
+/// use lenient_bool::nom::lenient_bool_token;
+/// use nom::error::ErrorKind;
+/// use nom::IResult;
+///
+/// let result: IResult<&str, bool, (&str, ErrorKind)> = lenient_bool_token("maybe");
+/// assert_eq!(result, Err(nom::Err::Error(("maybe", ErrorKind::Tag))));
+/// ```
+pub fn lenient_bool_token<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, bool, E> {
+    match parse_prefix(input) {
+        Some((value, len)) => Ok((&input[len..], value)),
+        None => Err(Err::Error(E::from_error_kind(input, ErrorKind::Tag))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::nom::error::Error;
+
+    use super::*;
+
+    fn parse(input: &str) -> IResult<&str, bool> {
+        lenient_bool(input)
+    }
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(parse("yes"), Ok(("", true)));
+    }
+
+    #[test]
+    fn parse_false_with_remainder() {
+        assert_eq!(parse("FALSE, 1"), Ok((", 1", false)));
+    }
+
+    #[test]
+    fn parse_longest_token() {
+        assert_eq!(parse("true)"), Ok((")", true)));
+    }
+
+    #[test]
+    fn parse_word_prefix_err() {
+        assert_eq!(
+            parse("yesterday"),
+            Err(Err::Error(Error::new("yesterday", ErrorKind::Tag)))
+        );
+    }
+
+    #[test]
+    fn custom_error_type() {
+        #[derive(Debug, PartialEq)]
+        struct ExprError(String);
+
+        impl ParseError<&str> for ExprError {
+            fn from_error_kind(input: &str, kind: ErrorKind) -> ExprError {
+                ExprError(format!("{:?} at {:?}", kind, input))
+            }
+
+            fn append(_: &str, _: ErrorKind, other: ExprError) -> ExprError {
+                other
+            }
+        }
+
+        assert_eq!(
+            lenient_bool_token::<ExprError>("no && x"),
+            Ok((" && x", false))
+        );
+        assert_eq!(
+            lenient_bool_token::<ExprError>("x"),
+            Err(Err::Error(ExprError("Tag at \"x\"".to_owned())))
+        );
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        assert_eq!(
+            parse("abc"),
+            Err(Err::Error(Error::new("abc", ErrorKind::Tag)))
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use core::ops::Deref;
+use core::str::FromStr;
+
+use super::{LenientBool, LenientBoolError};
+
+/// A boolean that may be explicitly missing, for tabular data with null markers.
+///
+/// The empty string and the markers in `NULL_MARKERS` parse as `None`, compared
+/// case-insensitively. Anything else is parsed with the `LenientBool` rules into
+/// `Some(bool)`.
+///
+/// ```
+/// use lenient_bool::LenientOptionBool;
+///
+/// assert_eq!("yes".parse(), Ok(LenientOptionBool(Some(true))));
+/// assert_eq!("N/A".parse(), Ok(LenientOptionBool(None)));
+/// assert_eq!("".parse(), Ok(LenientOptionBool(None)));
+/// assert!("maybe".parse::<LenientOptionBool>().is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct LenientOptionBool(pub Option<bool>);
+
+impl LenientOptionBool {
+    /// The strings, other than the empty string, that mean a value is missing.
+    pub const NULL_MARKERS: &'static [&'static str] = &["null", "none", "n/a", "-"];
+}
+
+impl FromStr for LenientOptionBool {
+    type Err = LenientBoolError;
+    fn from_str(s: &str) -> Result<LenientOptionBool, LenientBoolError> {
+        if s.is_empty()
+            || LenientOptionBool::NULL_MARKERS
+                .iter()
+                .any(|m| s.eq_ignore_ascii_case(m))
+        {
+            return Ok(LenientOptionBool(None));
+        }
+        s.parse::<LenientBool>()
+            .map(|b| LenientOptionBool(Some(b.0)))
+    }
+}
+
+impl From<LenientOptionBool> for Option<bool> {
+    fn from(b: LenientOptionBool) -> Option<bool> {
+        b.0
+    }
+}
+
+impl From<Option<bool>> for LenientOptionBool {
+    fn from(b: Option<bool>) -> LenientOptionBool {
+        LenientOptionBool(b)
+    }
+}
+
+impl Deref for LenientOptionBool {
+    type Target = Option<bool>;
+    fn deref(&self) -> &Option<bool> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn null_markers() {
+        for s in ["", "NULL", "None", "n/a", "-"] {
+            assert_eq!(s.parse(), Ok(LenientOptionBool(None)), "{}", s);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-single-letter"))]
+    fn values() {
+        assert_eq!("0".parse(), Ok(LenientOptionBool(Some(false))));
+        assert_eq!("T".parse(), Ok(LenientOptionBool(Some(true))));
+    }
+
+    #[test]
+    fn bad_input_err() {
+        assert!(" ".parse::<LenientOptionBool>().is_err());
+        assert!("nil".parse::<LenientOptionBool>().is_err());
+    }
+}
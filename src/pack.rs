@@ -0,0 +1,294 @@
+/// An extra set of spellings that can be added to a `LenientBoolParser` with `with_pack`.
+///
+/// ```
+/// use lenient_bool::{LenientBoolParser, TokenPack};
+///
+/// let parser = LenientBoolParser::new().with_pack(TokenPack::excel("de-DE").unwrap());
+/// assert_eq!(parser.parse("WAHR"), Ok(true));
+/// assert_eq!(parser.parse("falsch"), Ok(false));
+/// assert_eq!(parser.parse("yes"), Ok(true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenPack {
+    true_tokens: &'static [&'static str],
+    false_tokens: &'static [&'static str],
+}
+
+/// Excel's boolean literals by language, as they appear in CSV files exported from a
+/// localized Excel.
+const EXCEL: &[(&str, TokenPack)] = &[
+    ("cs", TokenPack::new(&["PRAVDA"], &["NEPRAVDA"])),
+    ("da", TokenPack::new(&["SAND"], &["FALSK"])),
+    ("de", TokenPack::new(&["WAHR"], &["FALSCH"])),
+    ("es", TokenPack::new(&["VERDADERO"], &["FALSO"])),
+    ("fi", TokenPack::new(&["TOSI"], &["EPÄTOSI"])),
+    ("fr", TokenPack::new(&["VRAI"], &["FAUX"])),
+    ("hu", TokenPack::new(&["IGAZ"], &["HAMIS"])),
+    ("it", TokenPack::new(&["VERO"], &["FALSO"])),
+    ("nb", TokenPack::new(&["SANN"], &["USANN"])),
+    ("nl", TokenPack::new(&["WAAR"], &["ONWAAR"])),
+    ("no", TokenPack::new(&["SANN"], &["USANN"])),
+    ("pl", TokenPack::new(&["PRAWDA"], &["FAŁSZ"])),
+    ("pt", TokenPack::new(&["VERDADEIRO"], &["FALSO"])),
+    ("ru", TokenPack::new(&["ИСТИНА"], &["ЛОЖЬ"])),
+    ("sv", TokenPack::new(&["SANT"], &["FALSKT"])),
+    ("tr", TokenPack::new(&["DOĞRU"], &["YANLIŞ"])),
+];
+
+/// Words for yes and no by language. The `locale-*` features add these to the default
+/// tokens, so they need to be kept in sync with `TRUE_TOKENS` and `FALSE_TOKENS`.
+const YES_NO: &[(&str, TokenPack)] = &[
+    ("de", TokenPack::new(&["ja"], &["nein"])),
+    ("es", TokenPack::new(&["sí", "si"], &["no"])),
+    ("fr", TokenPack::new(&["oui"], &["non"])),
+    ("ja", TokenPack::new(&["はい"], &["いいえ"])),
+];
+
+fn find(table: &[(&str, TokenPack)], locale: &str) -> Option<TokenPack> {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    table
+        .iter()
+        .find(|(l, _)| l.eq_ignore_ascii_case(language))
+        .map(|(_, pack)| *pack)
+}
+
+impl TokenPack {
+    /// `enable` and `enabled` as `true`, and `disable` and `disabled` as `false`, as used in
+    /// firmware and device config files.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new().with_pack(TokenPack::ENABLE_DISABLE);
+    /// assert_eq!(parser.parse("Enabled"), Ok(true));
+    /// assert_eq!(parser.parse("DISABLE"), Ok(false));
+    /// ```
+    pub const ENABLE_DISABLE: TokenPack =
+        TokenPack::new(&["enable", "enabled"], &["disable", "disabled"]);
+
+    /// Number words: `one` as `true`, and `zero`, `nil`, and `none` as `false`.
+    ///
+    /// These show up in voice-transcribed and other natural-language data, where a spoken
+    /// `1` or `0` gets written out as a word.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new().with_pack(TokenPack::NUMBER_WORDS);
+    /// assert_eq!(parser.parse("One"), Ok(true));
+    /// assert_eq!(parser.parse("none"), Ok(false));
+    /// ```
+    pub const NUMBER_WORDS: TokenPack = TokenPack::new(&["one"], &["zero", "nil", "none"]);
+
+    /// `on` as `true` and `off` as `false`, as used in INI-style config files and shell
+    /// environment variables.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new().with_pack(TokenPack::ON_OFF);
+    /// assert_eq!(parser.parse("ON"), Ok(true));
+    /// assert_eq!(parser.parse("off"), Ok(false));
+    /// ```
+    pub const ON_OFF: TokenPack = TokenPack::new(&["on"], &["off"]);
+
+    /// Pin and relay states: `high`, `open`, `set`, and `asserted` as `true`, and `low`,
+    /// `closed`, `clear`, and `deasserted` as `false`.
+    ///
+    /// These are common in embedded and IoT telemetry that logs signal states as strings.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new().with_pack(TokenPack::HARDWARE);
+    /// assert_eq!(parser.parse("HIGH"), Ok(true));
+    /// assert_eq!(parser.parse("deasserted"), Ok(false));
+    /// ```
+    pub const HARDWARE: TokenPack = TokenPack::new(
+        &["high", "open", "set", "asserted"],
+        &["low", "closed", "clear", "deasserted"],
+    );
+
+    /// English synonyms found in QA result sheets and operator logs: `ok`, `okay`, `pass`,
+    /// `passed`, `affirmative`, `accept`, and `accepted` as `true`, and `fail`, `failed`,
+    /// `negative`, `reject`, and `rejected` as `false`.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new().with_pack(TokenPack::SYNONYMS);
+    /// assert_eq!(parser.parse("OK"), Ok(true));
+    /// assert_eq!(parser.parse("Fail"), Ok(false));
+    /// assert_eq!(parser.parse("affirmative"), Ok(true));
+    /// ```
+    pub const SYNONYMS: TokenPack = TokenPack::new(
+        &[
+            "ok",
+            "okay",
+            "pass",
+            "passed",
+            "affirmative",
+            "accept",
+            "accepted",
+        ],
+        &["fail", "failed", "negative", "reject", "rejected"],
+    );
+
+    /// Check marks and crosses: `✓`, `✔`, `☑`, and `✅` as `true`, and `✗`, `✘`, `☒`, and `❌`
+    /// as `false`.
+    ///
+    /// These turn up in spreadsheets where people paste symbols into boolean columns. The
+    /// emoji presentation forms, with a trailing U+FE0F variation selector, are included too.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new().with_pack(TokenPack::CHECK_MARKS);
+    /// assert_eq!(parser.parse("✓"), Ok(true));
+    /// assert_eq!(parser.parse("❌"), Ok(false));
+    /// ```
+    pub const CHECK_MARKS: TokenPack = TokenPack::new(
+        &["✓", "✔", "✔\u{fe0f}", "☑", "☑\u{fe0f}", "✅"],
+        &["✗", "✘", "☒", "❌"],
+    );
+
+    /// Creates a pack from lists of spellings for `true` and `false`.
+    pub const fn new(
+        true_tokens: &'static [&'static str],
+        false_tokens: &'static [&'static str],
+    ) -> TokenPack {
+        TokenPack {
+            true_tokens,
+            false_tokens,
+        }
+    }
+
+    /// The `TRUE` and `FALSE` literals used by Excel in `locale`.
+    ///
+    /// `locale` is a language tag such as `de`, `pt-BR`, or `fr_CA`. Only the language is
+    /// used, since Excel's literals don't vary by region. Returns `None` for English, which
+    /// the default tokens already cover, and for unknown languages.
+    pub fn excel(locale: &str) -> Option<TokenPack> {
+        find(EXCEL, locale)
+    }
+
+    /// The words for yes and no in `locale`, such as `oui` and `non` for French.
+    ///
+    /// `locale` is handled as in `excel`. German, Spanish, French, and Japanese are
+    /// available, and can also be added to the default tokens with the `locale-de`,
+    /// `locale-es`, `locale-fr`, and `locale-ja` features.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new().with_pack(TokenPack::yes_no("fr-CA").unwrap());
+    /// assert_eq!(parser.parse("Oui"), Ok(true));
+    /// assert_eq!(parser.parse("non"), Ok(false));
+    /// ```
+    pub fn yes_no(locale: &str) -> Option<TokenPack> {
+        find(YES_NO, locale)
+    }
+
+    /// The spellings in this pack that parse as `true`.
+    pub fn true_tokens(&self) -> &'static [&'static str] {
+        self.true_tokens
+    }
+
+    /// The spellings in this pack that parse as `false`.
+    pub fn false_tokens(&self) -> &'static [&'static str] {
+        self.false_tokens
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn excel_language() {
+        assert_eq!(TokenPack::excel("fr").unwrap().true_tokens(), ["VRAI"]);
+    }
+
+    #[test]
+    fn excel_region() {
+        assert_eq!(
+            TokenPack::excel("pt_BR").unwrap().true_tokens(),
+            ["VERDADEIRO"]
+        );
+    }
+
+    #[test]
+    fn excel_unknown() {
+        assert_eq!(TokenPack::excel("en-US"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn yes_no() {
+        let parser = crate::LenientBoolParser::new().with_pack(TokenPack::yes_no("es").unwrap());
+        assert_eq!(parser.parse("SÍ"), Ok(true));
+        assert_eq!(TokenPack::yes_no("ja").unwrap().false_tokens(), ["いいえ"]);
+        assert_eq!(TokenPack::yes_no("en"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn number_words() {
+        let parser = crate::LenientBoolParser::new().with_pack(TokenPack::NUMBER_WORDS);
+        assert_eq!(parser.parse("ZERO"), Ok(false));
+        assert_eq!(parser.parse("nil"), Ok(false));
+        assert!(parser.parse("two").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn on_off() {
+        let parser = crate::LenientBoolParser::new().with_pack(TokenPack::ON_OFF);
+        assert_eq!(parser.parse("On"), Ok(true));
+        assert_eq!(parser.parse("OFF"), Ok(false));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn enable_disable() {
+        let parser = crate::LenientBoolParser::new().with_pack(TokenPack::ENABLE_DISABLE);
+        assert_eq!(parser.parse("enable"), Ok(true));
+        assert_eq!(parser.parse("Disabled"), Ok(false));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hardware() {
+        let parser = crate::LenientBoolParser::new().with_pack(TokenPack::HARDWARE);
+        assert_eq!(parser.parse("Open"), Ok(true));
+        assert_eq!(parser.parse("closed"), Ok(false));
+        assert_eq!(parser.parse("SET"), Ok(true));
+        assert_eq!(parser.parse("clear"), Ok(false));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn synonyms() {
+        let parser = crate::LenientBoolParser::new().with_pack(TokenPack::SYNONYMS);
+        for s in ["Okay", "PASS", "passed", "Accept", "accepted"] {
+            assert_eq!(parser.parse(s), Ok(true), "{}", s);
+        }
+        for s in ["FAILED", "Negative", "reject", "Rejected"] {
+            assert_eq!(parser.parse(s), Ok(false), "{}", s);
+        }
+        assert!(parser.parse("pending").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn check_marks() {
+        let parser = crate::LenientBoolParser::new().with_pack(TokenPack::CHECK_MARKS);
+        for s in ["✓", "✔", "✔️", "☑", "✅"] {
+            assert_eq!(parser.parse(s), Ok(true), "{}", s);
+        }
+        for s in ["✗", "✘", "☒", "❌"] {
+            assert_eq!(parser.parse(s), Ok(false), "{}", s);
+        }
+        assert!(parser.parse("✓✓").is_err());
+    }
+}
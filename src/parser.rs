@@ -0,0 +1,1934 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::OnceLock;
+
+use super::{
+    eq_ignore_case, fold, BoolParser, LenientBool, LenientBoolError, TokenPack, FALSE_TOKENS,
+    TRUE_TOKENS,
+};
+
+thread_local! {
+    static CURRENT: RefCell<Option<Rc<LenientBoolParser>>> = const { RefCell::new(None) };
+}
+
+static DEFAULT: OnceLock<LenientBoolParser> = OnceLock::new();
+
+/// How `LenientBoolParser` compares input to its tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Sensitive,
+    /// Ignoring case with Unicode case folding, like `LenientBool`.
+    Insensitive,
+    /// Ignoring only ASCII case, for profiles that have to match other parsers byte for byte.
+    AsciiInsensitive,
+}
+
+fn matches(case: Case, s: &str, token: &str) -> bool {
+    match case {
+        Case::Sensitive => s == token,
+        Case::Insensitive => eq_ignore_case(s, token),
+        Case::AsciiInsensitive => s.eq_ignore_ascii_case(token),
+    }
+}
+
+/// What `LenientBoolParser` trims from both ends of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trim {
+    Off,
+    /// Whitespace and NUL characters.
+    Whitespace,
+    /// The characters that C's `isspace` matches in the C locale.
+    CSpace,
+}
+
+/// A configurable boolean parser.
+///
+/// `LenientBoolParser::new()` follows the same rules as `LenientBool`. The other constructors
+/// are profiles that match the boolean parsing of other languages and libraries exactly, for
+/// code that has to agree with them.
+///
+/// ```
+/// use lenient_bool::LenientBoolParser;
+///
+/// let go = LenientBoolParser::go();
+/// assert_eq!(go.parse("True"), Ok(true));
+/// assert!(go.parse("tRUE").is_err());
+/// assert!(go.parse("yes").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenientBoolParser {
+    tokens: Vec<Entry>,
+    case: Case,
+    trim: Trim,
+    fallback: Option<bool>,
+    canonical: Option<(&'static str, &'static str)>,
+    numeric: NumericMode,
+    negation: bool,
+    strip_quotes: bool,
+    trailing_punctuation: bool,
+    fuzzy: usize,
+    /// The position of each token in `tokens`, keyed by its folded text. Only a
+    /// `CompiledParser` has one.
+    index: Option<HashMap<String, usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    text: Cow<'static, str>,
+    value: bool,
+    deprecated: bool,
+    /// Whether the token only matches when spelled exactly, whatever the parser's case
+    /// sensitivity.
+    exact_case: bool,
+}
+
+impl Entry {
+    fn new(text: &'static str, value: bool) -> Entry {
+        Entry {
+            text: Cow::Borrowed(text),
+            value,
+            deprecated: false,
+            exact_case: false,
+        }
+    }
+
+    /// Whether the token should be listed in help output.
+    fn is_advertised(&self) -> bool {
+        !self.deprecated && !self.text.is_empty()
+    }
+
+    fn matches(&self, case: Case, s: &str) -> bool {
+        if self.exact_case {
+            s == self.text
+        } else {
+            matches(case, s, &self.text)
+        }
+    }
+}
+
+/// How `LenientBoolParser` treats numeric strings other than its tokens.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NumericMode {
+    /// Only numbers that are tokens, such as `1` and `0`, are accepted.
+    #[default]
+    Tokens,
+    /// Also accept `1` and `0` with leading zeros, such as `01`, `00`, and `000`, as written
+    /// by fixed-width exports.
+    ZeroPadded,
+    /// Accept any integer, with zero as `false` and anything else as `true`, like C. This
+    /// suits legacy exports that store flags as counts.
+    Nonzero,
+    /// Accept decimal numbers equal to 1 or 0, such as `1.0` and `0.00`, as spreadsheets
+    /// often write booleans.
+    Decimal,
+    /// Accept any decimal number, with numbers at or above the threshold as `true`.
+    /// Infinities and NaN are rejected.
+    Threshold(f64),
+}
+
+// Thresholds are compared bit for bit, so that `LenientBoolParser` can stay `Eq`.
+impl PartialEq for NumericMode {
+    fn eq(&self, other: &NumericMode) -> bool {
+        match (self, other) {
+            (NumericMode::Threshold(a), NumericMode::Threshold(b)) => a.to_bits() == b.to_bits(),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for NumericMode {}
+
+/// Parses `s` as a finite decimal number. Unlike `f64::from_str`, this rejects `inf` and
+/// `NaN`.
+fn parse_decimal(s: &str) -> Option<f64> {
+    let is_numeric = s
+        .bytes()
+        .all(|b| b.is_ascii_digit() || matches!(b, b'+' | b'-' | b'.' | b'e' | b'E'));
+    if !is_numeric {
+        return None;
+    }
+    s.parse::<f64>().ok().filter(|f| f.is_finite())
+}
+
+impl NumericMode {
+    fn parse(self, s: &str) -> Option<bool> {
+        match self {
+            NumericMode::Tokens => None,
+            NumericMode::ZeroPadded => {
+                if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                match s.trim_start_matches('0') {
+                    "" => Some(false),
+                    "1" => Some(true),
+                    _ => None,
+                }
+            }
+            NumericMode::Nonzero => {
+                let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                Some(digits.bytes().any(|b| b != b'0'))
+            }
+            NumericMode::Decimal => match parse_decimal(s)? {
+                0.0 => Some(false),
+                1.0 => Some(true),
+                _ => None,
+            },
+            NumericMode::Threshold(threshold) => parse_decimal(s).map(|f| f >= threshold),
+        }
+    }
+}
+
+/// How `LenientBoolParser::help_text` lays out the accepted values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HelpStyle<'a> {
+    /// A single comma-separated list, such as `true, yes, false, no`.
+    #[default]
+    Flat,
+    /// The `true` and `false` spellings listed separately under the given labels, such as
+    /// `true: true, yes; false: false, no`. The labels can be localized.
+    Grouped {
+        true_label: &'a str,
+        false_label: &'a str,
+    },
+}
+
+/// A token matched by `LenientBoolParser::parse_fuzzy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyMatch<'a> {
+    pub value: bool,
+    /// The token that matched, as spelled in the parser's token table.
+    pub token: &'a str,
+    /// The number of edits between the input and `token`, where `0` is an exact match.
+    pub distance: usize,
+}
+
+/// The optimal string alignment distance between `a` and `b`: the number of insertions,
+/// deletions, substitutions, and transpositions of adjacent characters needed to turn one into
+/// the other. Returns `None` as soon as it's clear that the distance is more than `max`.
+fn edit_distance(a: &[char], b: &[char], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    // Only the last three rows of the table are needed, the oldest for transpositions.
+    let mut older = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut row = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (previous[j] + 1)
+                .min(row[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(older[j - 2] + 1);
+            }
+        }
+        // Distances never shrink from one row to the next, so the rest can't come back
+        // under `max`.
+        if row.iter().min().is_some_and(|&m| m > max) {
+            return None;
+        }
+        std::mem::swap(&mut older, &mut previous);
+        std::mem::swap(&mut previous, &mut row);
+    }
+    Some(previous[b.len()]).filter(|&d| d <= max)
+}
+
+/// A problem with a value that was still parsed successfully, reported by
+/// `LenientBoolParser::parse_with_lint`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Lint {
+    /// The value isn't spelled the way the profile prefers.
+    NonCanonical {
+        found: String,
+        canonical: &'static str,
+    },
+    /// The value is a spelling that has been marked as deprecated.
+    Deprecated { found: String },
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lint::NonCanonical { found, canonical } => write!(
+                f,
+                "non-canonical boolean value {:?}, expected {:?}",
+                found, canonical
+            ),
+            Lint::Deprecated { found } => write!(f, "deprecated boolean value {:?}", found),
+        }
+    }
+}
+
+impl LenientBoolParser {
+    /// A parser with the `LenientBool` rules.
+    pub fn new() -> LenientBoolParser {
+        LenientBoolParser::from_tokens(TRUE_TOKENS, FALSE_TOKENS, Case::Insensitive)
+    }
+
+    fn from_tokens(
+        true_tokens: &[&'static str],
+        false_tokens: &[&'static str],
+        case: Case,
+    ) -> LenientBoolParser {
+        let true_tokens = true_tokens.iter().map(|t| Entry::new(t, true));
+        let false_tokens = false_tokens.iter().map(|t| Entry::new(t, false));
+        LenientBoolParser {
+            tokens: true_tokens.chain(false_tokens).collect(),
+            case,
+            trim: Trim::Off,
+            fallback: None,
+            canonical: None,
+            numeric: NumericMode::Tokens,
+            negation: false,
+            strip_quotes: false,
+            trailing_punctuation: false,
+            fuzzy: 0,
+            index: None,
+        }
+    }
+
+    /// A parser that matches Go's `strconv.ParseBool` exactly.
+    ///
+    /// It accepts `1`, `t`, `T`, `TRUE`, `true`, and `True` as `true`, and `0`, `f`, `F`,
+    /// `FALSE`, `false`, and `False` as `false`. Other capitalizations are rejected.
+    pub fn go() -> LenientBoolParser {
+        LenientBoolParser::from_tokens(
+            &["1", "t", "T", "TRUE", "true", "True"],
+            &["0", "f", "F", "FALSE", "false", "False"],
+            Case::Sensitive,
+        )
+    }
+
+    /// A parser that matches Python's `distutils.util.strtobool`.
+    ///
+    /// It accepts `y`, `yes`, `t`, `true`, `on`, and `1` as `true`, and `n`, `no`, `f`,
+    /// `false`, `off`, and `0` as `false`, ignoring ASCII case.
+    pub fn python() -> LenientBoolParser {
+        LenientBoolParser::from_tokens(
+            &["y", "yes", "t", "true", "on", "1"],
+            &["n", "no", "f", "false", "off", "0"],
+            Case::AsciiInsensitive,
+        )
+    }
+
+    /// A parser that matches .NET's `bool.Parse` and `Convert.ToBoolean(string)`.
+    ///
+    /// `true` and `false` are matched case-insensitively, after trimming whitespace and NUL
+    /// characters from both ends.
+    pub fn dotnet() -> LenientBoolParser {
+        LenientBoolParser {
+            trim: Trim::Whitespace,
+            ..LenientBoolParser::from_tokens(&["true"], &["false"], Case::Insensitive)
+        }
+    }
+
+    /// A parser that matches Java's `Boolean.parseBoolean`.
+    ///
+    /// `true` is matched case-insensitively, and everything else, including the empty
+    /// string, is `false`. This parser never returns an error.
+    pub fn java() -> LenientBoolParser {
+        LenientBoolParser {
+            fallback: Some(false),
+            ..LenientBoolParser::from_tokens(&["true"], &[], Case::Insensitive)
+        }
+    }
+
+    /// A parser that matches PostgreSQL's boolean input syntax exactly.
+    ///
+    /// Like the server, it accepts `true`, `false`, `yes`, `no`, and any prefix of them, such
+    /// as `tru` and `f`, along with `on`, `off`, `of`, `1`, and `0`. Letters are matched
+    /// ignoring ASCII case, and ASCII whitespace is trimmed from both ends.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let pg = LenientBoolParser::postgres();
+    /// assert_eq!(pg.parse(" Tru "), Ok(true));
+    /// assert_eq!(pg.parse("of"), Ok(false));
+    /// assert!(pg.parse("o").is_err());
+    /// assert!(pg.parse("01").is_err());
+    /// ```
+    pub fn postgres() -> LenientBoolParser {
+        LenientBoolParser {
+            trim: Trim::CSpace,
+            ..LenientBoolParser::from_tokens(
+                &["t", "tr", "tru", "true", "y", "ye", "yes", "on", "1"],
+                &[
+                    "f", "fa", "fal", "fals", "false", "n", "no", "of", "off", "0",
+                ],
+                Case::AsciiInsensitive,
+            )
+        }
+    }
+
+    /// A parser for Kubernetes annotations and Helm values.
+    ///
+    /// These are meant to be the strings `true` and `false`, but chart templating often
+    /// produces other spellings such as `True`, `1`, or `yes`. This parser accepts all of the
+    /// `LenientBool` spellings, and `parse_with_lint` reports the ones that aren't `true` or
+    /// `false`.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, Lint};
+    ///
+    /// let parser = LenientBoolParser::kubernetes();
+    /// assert_eq!(parser.parse_with_lint("true"), Ok((true, vec![])));
+    ///
+    /// let (value, lints) = parser.parse_with_lint("True").unwrap();
+    /// assert!(value);
+    /// assert_eq!(lints[0].to_string(), r#"non-canonical boolean value "True", expected "true""#);
+    /// ```
+    pub fn kubernetes() -> LenientBoolParser {
+        LenientBoolParser {
+            canonical: Some(("true", "false")),
+            ..LenientBoolParser::new()
+        }
+    }
+
+    /// A parser that matches the YAML 1.1 boolean type exactly, for migrating YAML 1.1
+    /// documents.
+    ///
+    /// It accepts `y`, `yes`, `true`, and `on` as `true`, and `n`, `no`, `false`, and `off` as
+    /// `false`, each in lowercase, capitalized, or uppercase, such as `Yes` and `YES`. Other
+    /// capitalizations are rejected.
+    pub fn yaml11() -> LenientBoolParser {
+        LenientBoolParser::from_tokens(
+            &[
+                "y", "Y", "yes", "Yes", "YES", "true", "True", "TRUE", "on", "On", "ON",
+            ],
+            &[
+                "n", "N", "no", "No", "NO", "false", "False", "FALSE", "off", "Off", "OFF",
+            ],
+            Case::Sensitive,
+        )
+    }
+
+    /// A parser for checkbox columns exported from Excel, Google Sheets, and other
+    /// spreadsheets as XLSX or CSV.
+    ///
+    /// It accepts `TRUE`, the `TRUE()` left behind when a formula is exported as text, `X`,
+    /// `✓`, `✔`, and `1` as `true`, and `FALSE`, `FALSE()`, `0`, and the empty cell as
+    /// `false`. Letters are matched ignoring case, and whitespace is trimmed from both ends,
+    /// so a cell holding only spaces is also `false`.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let parser = LenientBoolParser::spreadsheet();
+    /// assert_eq!(parser.parse("TRUE()"), Ok(true));
+    /// assert_eq!(parser.parse("x"), Ok(true));
+    /// assert_eq!(parser.parse("✓"), Ok(true));
+    /// assert_eq!(parser.parse(""), Ok(false));
+    /// assert_eq!(parser.parse("0"), Ok(false));
+    /// assert!(parser.parse("yes").is_err());
+    /// ```
+    pub fn spreadsheet() -> LenientBoolParser {
+        LenientBoolParser {
+            trim: Trim::Whitespace,
+            ..LenientBoolParser::from_tokens(
+                &["TRUE", "TRUE()", "X", "✓", "✔", "1"],
+                &["FALSE", "FALSE()", "0", ""],
+                Case::Insensitive,
+            )
+        }
+    }
+
+    /// Adds the spellings in `pack` to this parser.
+    pub fn with_pack(mut self, pack: TokenPack) -> LenientBoolParser {
+        let true_tokens = pack.true_tokens().iter().map(|t| Entry::new(t, true));
+        let false_tokens = pack.false_tokens().iter().map(|t| Entry::new(t, false));
+        self.tokens.extend(true_tokens.chain(false_tokens));
+        self
+    }
+
+    /// Replaces the spellings that parse as `true`.
+    ///
+    /// Together with `false_tokens`, this can restrict the accepted values as well as extend
+    /// them. Use `with_pack` to add spellings while keeping the existing ones.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let parser = LenientBoolParser::new()
+    ///     .true_tokens(["aktiv", "true"])
+    ///     .false_tokens(["inaktiv", "false"]);
+    /// assert_eq!(parser.parse("Aktiv"), Ok(true));
+    /// assert_eq!(parser.parse("inaktiv"), Ok(false));
+    /// assert!(parser.parse("yes").is_err());
+    /// ```
+    pub fn true_tokens<I>(self, tokens: I) -> LenientBoolParser
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        self.replace_tokens(true, tokens)
+    }
+
+    /// Replaces the spellings that parse as `false`. See `true_tokens`.
+    pub fn false_tokens<I>(self, tokens: I) -> LenientBoolParser
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        self.replace_tokens(false, tokens)
+    }
+
+    fn replace_tokens<I>(mut self, value: bool, tokens: I) -> LenientBoolParser
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        self.tokens.retain(|entry| entry.value != value);
+        self.tokens.extend(tokens.into_iter().map(|text| Entry {
+            text: text.into(),
+            value,
+            deprecated: false,
+            exact_case: false,
+        }));
+        self
+    }
+
+    /// Sets whether tokens are matched ignoring case. This is on for `new()`.
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> LenientBoolParser {
+        self.case = if case_insensitive {
+            Case::Insensitive
+        } else {
+            Case::Sensitive
+        };
+        self
+    }
+
+    /// Sets whether whitespace and NUL characters are trimmed from both ends of the input
+    /// before matching. This is off for `new()`.
+    pub fn trim(mut self, trim: bool) -> LenientBoolParser {
+        self.trim = if trim { Trim::Whitespace } else { Trim::Off };
+        self
+    }
+
+    /// Sets how numeric strings that aren't tokens are handled.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, NumericMode};
+    ///
+    /// let parser = LenientBoolParser::new().numeric(NumericMode::ZeroPadded);
+    /// assert_eq!(parser.parse("001"), Ok(true));
+    /// assert_eq!(parser.parse("000"), Ok(false));
+    /// assert!(parser.parse("002").is_err());
+    /// ```
+    pub fn numeric(mut self, mode: NumericMode) -> LenientBoolParser {
+        self.numeric = mode;
+        self
+    }
+
+    /// Sets whether a leading `!`, `not `, or `no ` negates the value that follows, as in
+    /// `!yes` or `not enabled`. This is off for `new()`.
+    ///
+    /// Negations can be repeated. Input that matches a token as a whole, such as `no`, is
+    /// never treated as a negation.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, TokenPack};
+    ///
+    /// let parser = LenientBoolParser::new()
+    ///     .with_pack(TokenPack::ENABLE_DISABLE)
+    ///     .negation(true);
+    /// assert_eq!(parser.parse("not enabled"), Ok(false));
+    /// assert_eq!(parser.parse("!0"), Ok(true));
+    /// assert_eq!(parser.parse("No true"), Ok(false));
+    /// assert_eq!(parser.parse("no"), Ok(false));
+    /// ```
+    pub fn negation(mut self, negation: bool) -> LenientBoolParser {
+        self.negation = negation;
+        self
+    }
+
+    /// Sets whether one layer of matching single or double quotes around the input is
+    /// removed before matching, as left behind in CSV and shell-sourced data. Quotes are
+    /// removed after trimming. This is off for `new()`.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let parser = LenientBoolParser::new().strip_quotes(true);
+    /// assert_eq!(parser.parse("\"true\""), Ok(true));
+    /// assert_eq!(parser.parse("'no'"), Ok(false));
+    /// assert!(parser.parse("\"yes'").is_err());
+    /// ```
+    pub fn strip_quotes(mut self, strip_quotes: bool) -> LenientBoolParser {
+        self.strip_quotes = strip_quotes;
+        self
+    }
+
+    /// Sets whether trailing punctuation, `.`, `,`, `;`, `:`, `!`, and `?`, and any whitespace
+    /// mixed in with it, is ignored, as in free-text survey answers such as `yes.` and
+    /// `no, `. It is removed after trimming and before quotes are removed. This is off for
+    /// `new()`.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let parser = LenientBoolParser::new().trailing_punctuation(true);
+    /// assert_eq!(parser.parse("yes."), Ok(true));
+    /// assert_eq!(parser.parse("No !!"), Ok(false));
+    /// assert!(parser.parse(".yes").is_err());
+    /// ```
+    pub fn trailing_punctuation(mut self, trailing_punctuation: bool) -> LenientBoolParser {
+        self.trailing_punctuation = trailing_punctuation;
+        self
+    }
+
+    /// Sets how many edits, counting insertions, deletions, substitutions, and swaps of
+    /// adjacent characters, an unrecognized value may be from a token and still match it, for
+    /// cleaning up hand-entered data with typos such as `ture` and `flase`. `0`, the default,
+    /// turns this off.
+    ///
+    /// A token only matches with fewer edits than both it and the input have characters, so
+    /// single-character tokens such as `y` and single-character input such as `a` are never
+    /// matched fuzzily. Case is ignored the same way as for exact matches. If the closest
+    /// tokens have different values, the input is rejected as ambiguous. `parse_fuzzy`
+    /// reports which token matched.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let parser = LenientBoolParser::new().fuzzy(1);
+    /// assert_eq!(parser.parse("ture"), Ok(true));
+    /// assert_eq!(parser.parse("FLASE"), Ok(false));
+    /// assert_eq!(parser.parse("yse"), Ok(true));
+    /// assert!(parser.parse("maybe").is_err());
+    /// ```
+    pub fn fuzzy(mut self, max_distance: usize) -> LenientBoolParser {
+        self.fuzzy = max_distance;
+        self
+    }
+
+    /// Marks `token` as deprecated.
+    ///
+    /// Deprecated spellings are still accepted, but `parse_with_lint` reports them, which
+    /// helps with migrating to a stricter set of values in stages. `token` is matched with
+    /// this parser's case sensitivity, and unknown tokens are ignored.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBoolParser, Lint};
+    ///
+    /// let parser = LenientBoolParser::new().deprecate("y").deprecate("n");
+    /// assert_eq!(parser.parse_with_lint("yes"), Ok((true, vec![])));
+    /// assert_eq!(
+    ///     parser.parse_with_lint("Y"),
+    ///     Ok((true, vec![Lint::Deprecated { found: "Y".to_owned() }]))
+    /// );
+    /// ```
+    pub fn deprecate(mut self, token: &str) -> LenientBoolParser {
+        let case = self.case;
+        for entry in &mut self.tokens {
+            if matches(case, token, &entry.text) {
+                entry.deprecated = true;
+            }
+        }
+        self
+    }
+
+    /// Makes `tokens` match only when spelled exactly as given, while the others keep this
+    /// parser's case sensitivity. This is for accepting, say, only lowercase `true` and
+    /// `false`, to catch non-canonical casing bound for a strict downstream system, while
+    /// still accepting `Y` and `n`.
+    ///
+    /// Each of `tokens` is looked up with this parser's case sensitivity, and unknown tokens
+    /// are ignored. The parser's own spelling of the token is the one required.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let parser = LenientBoolParser::new().exact_case(["true", "false"]);
+    /// assert_eq!(parser.parse("true"), Ok(true));
+    /// assert!(parser.parse("True").is_err());
+    /// assert_eq!(parser.parse("Y"), Ok(true));
+    /// assert_eq!(parser.parse("n"), Ok(false));
+    /// ```
+    pub fn exact_case<I>(mut self, tokens: I) -> LenientBoolParser
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let case = self.case;
+        for token in tokens {
+            for entry in &mut self.tokens {
+                if matches(case, token.as_ref(), &entry.text) {
+                    entry.exact_case = true;
+                }
+            }
+        }
+        self
+    }
+
+    /// Folds `s` with this parser's case sensitivity, for looking it up in the index.
+    fn key<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self.case {
+            Case::Sensitive => Cow::Borrowed(s),
+            Case::Insensitive if s.is_ascii() => Cow::Owned(s.to_ascii_lowercase()),
+            Case::Insensitive => Cow::Owned(s.chars().flat_map(fold).collect()),
+            Case::AsciiInsensitive => Cow::Owned(s.to_ascii_lowercase()),
+        }
+    }
+
+    /// Builds a hash index of the tokens, so that exact matching takes the same time however
+    /// many tokens there are, rather than comparing the input to each of them in turn. This is
+    /// worth it for parsers with hundreds of spellings, such as several locale packs and
+    /// domain synonyms.
+    ///
+    /// Only exact lookups use the index. With `fuzzy` on, input that doesn't match exactly is
+    /// still compared to each token of a similar length.
+    ///
+    /// ```
+    /// use lenient_bool::LenientBoolParser;
+    ///
+    /// let synonyms = (0..500).map(|i| format!("synonym-{}", i));
+    /// let parser = LenientBoolParser::new()
+    ///     .true_tokens(synonyms)
+    ///     .compile();
+    /// assert_eq!(parser.parse("SYNONYM-499"), Ok(true));
+    /// assert_eq!(parser.parse("no"), Ok(false));
+    /// ```
+    pub fn compile(mut self) -> CompiledParser {
+        let mut index = HashMap::with_capacity(self.tokens.len());
+        for (i, entry) in self.tokens.iter().enumerate() {
+            // Earlier tokens win, as they do when matching one by one.
+            index.entry(self.key(&entry.text).into_owned()).or_insert(i);
+        }
+        self.index = Some(index);
+        CompiledParser(self)
+    }
+
+    fn find(&self, s: &str) -> Option<&Entry> {
+        if let Some(index) = &self.index {
+            match index.get(&*self.key(s)).map(|&i| &self.tokens[i]) {
+                // Another token with the same folded text may still match, so fall back to
+                // checking each of them.
+                Some(entry) if entry.exact_case && s != entry.text => {}
+                found => return found,
+            }
+        }
+        self.tokens.iter().find(|entry| entry.matches(self.case, s))
+    }
+
+    /// Finds the closest token to `s` within the `fuzzy` distance.
+    fn find_fuzzy(&self, s: &str) -> Option<FuzzyMatch<'_>> {
+        if let Some(entry) = self.find(s) {
+            return Some(FuzzyMatch {
+                value: entry.value,
+                token: &entry.text,
+                distance: 0,
+            });
+        }
+        if self.fuzzy == 0 {
+            return None;
+        }
+        let fold = |s: &str| -> Vec<char> {
+            match self.case {
+                Case::Sensitive => s.chars().collect(),
+                Case::Insensitive => s.chars().flat_map(fold).collect(),
+                Case::AsciiInsensitive => s.chars().map(|c| c.to_ascii_lowercase()).collect(),
+            }
+        };
+        // Folding never makes a string shorter, so tokens that are too short to be within the
+        // distance can be skipped without folding the input, which may be long junk.
+        let len = s.chars().count();
+        let mut folded_input = None;
+        let mut exact_input = None;
+        let mut best: Option<FuzzyMatch> = None;
+        let mut ambiguous = false;
+        for entry in &self.tokens {
+            let token = if entry.exact_case {
+                entry.text.chars().collect()
+            } else {
+                fold(&entry.text)
+            };
+            if len > token.len() + self.fuzzy {
+                continue;
+            }
+            let input: &Vec<char> = if entry.exact_case {
+                exact_input.get_or_insert_with(|| s.chars().collect())
+            } else {
+                folded_input.get_or_insert_with(|| fold(s))
+            };
+            let Some(distance) = edit_distance(input, &token, self.fuzzy) else {
+                continue;
+            };
+            if distance >= token.len() || distance >= input.len() {
+                continue;
+            }
+            match best {
+                Some(b) if distance > b.distance => {}
+                Some(b) if distance == b.distance => ambiguous |= b.value != entry.value,
+                _ => {
+                    ambiguous = false;
+                    best = Some(FuzzyMatch {
+                        value: entry.value,
+                        token: &entry.text,
+                        distance,
+                    });
+                }
+            }
+        }
+        best.filter(|_| !ambiguous)
+    }
+
+    fn trimmed<'a>(&self, s: &'a str) -> &'a str {
+        match self.trim {
+            Trim::Off => s,
+            Trim::Whitespace => s.trim_matches(|c: char| c.is_whitespace() || c == '\0'),
+            Trim::CSpace => s.trim_matches([' ', '\t', '\n', '\r', '\x0b', '\x0c']),
+        }
+    }
+
+    /// Trims `s` and removes punctuation and quotes from it, as configured. With the `nfkc`
+    /// feature, non-ASCII input is normalized first, as `LenientBool::from_str` does, unless
+    /// the parser compares case or bytes exactly.
+    fn prepared<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        #[cfg(feature = "nfkc")]
+        if self.case == Case::Insensitive && !s.is_ascii() {
+            use ::unicode_normalization::UnicodeNormalization;
+            let normalized: String = s.nfkc().collect();
+            return Cow::Owned(self.stripped(&normalized).to_owned());
+        }
+        Cow::Borrowed(self.stripped(s))
+    }
+
+    fn stripped<'a>(&self, s: &'a str) -> &'a str {
+        let mut s = self.trimmed(s);
+        if self.trailing_punctuation {
+            s = s.trim_end_matches(|c: char| {
+                matches!(c, '.' | ',' | ';' | ':' | '!' | '?') || c.is_whitespace()
+            });
+        }
+        if !self.strip_quotes {
+            return s;
+        }
+        ['"', '\'']
+            .iter()
+            .find_map(|q| s.strip_prefix(*q)?.strip_suffix(*q))
+            .unwrap_or(s)
+    }
+
+    /// Matches `s`, which has already been prepared, against the tokens and numeric mode.
+    fn value(&self, s: &str) -> Option<bool> {
+        let exact = |s: &str| {
+            self.find(s)
+                .map(|entry| entry.value)
+                .or_else(|| self.numeric.parse(s))
+        };
+        if let Some(value) = exact(s) {
+            return Some(value);
+        }
+
+        // Every negation is removed before matching what is left, so that long chains of them
+        // take linear time.
+        let mut rest = s;
+        let mut negated = false;
+        while let Some(stripped) = self.strip_negation(rest) {
+            rest = stripped.trim_start();
+            negated = !negated;
+        }
+        if rest.len() < s.len() {
+            // A negation with nothing after it must not match an empty token.
+            if rest.is_empty() {
+                return None;
+            }
+            if let Some(value) = exact(rest) {
+                return Some(value != negated);
+            }
+        }
+        if self.fuzzy == 0 {
+            return None;
+        }
+        self.find_fuzzy(rest).map(|m| m.value != negated)
+    }
+
+    /// Removes a leading negation from `s`, if negation is on.
+    fn strip_negation<'a>(&self, s: &'a str) -> Option<&'a str> {
+        if !self.negation {
+            return None;
+        }
+        let strip = |prefix: &str| {
+            s.get(..prefix.len())
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| &s[prefix.len()..])
+        };
+        s.strip_prefix('!')
+            .or_else(|| strip("not "))
+            .or_else(|| strip("no "))
+    }
+
+    /// Parses `s` with this parser's rules.
+    pub fn parse(&self, s: &str) -> Result<bool, LenientBoolError> {
+        self.value(&self.prepared(s))
+            .or(self.fallback)
+            .ok_or_else(|| LenientBoolError::new(s))
+    }
+
+    /// Matches `s` against the tokens, allowing for typos up to the `fuzzy` distance, and
+    /// reports the token that matched and how far the input was from it. Exact matches have a
+    /// distance of `0`. The numeric mode, negation, and fallback don't apply.
+    ///
+    /// ```
+    /// use lenient_bool::{FuzzyMatch, LenientBoolParser};
+    ///
+    /// let parser = LenientBoolParser::new().fuzzy(2);
+    /// assert_eq!(
+    ///     parser.parse_fuzzy("flsae"),
+    ///     Ok(FuzzyMatch { value: false, token: "false", distance: 2 })
+    /// );
+    /// ```
+    pub fn parse_fuzzy(&self, s: &str) -> Result<FuzzyMatch<'_>, LenientBoolError> {
+        self.find_fuzzy(&self.prepared(s))
+            .ok_or_else(|| LenientBoolError::new(s))
+    }
+
+    /// Parses `s`, also reporting any `Lint`s for values that were accepted but are
+    /// discouraged, such as deprecated or non-canonical spellings.
+    pub fn parse_with_lint(&self, s: &str) -> Result<(bool, Vec<Lint>), LenientBoolError> {
+        let value = self.parse(s)?;
+        let mut lints = Vec::new();
+        if self
+            .find(&self.prepared(s))
+            .is_some_and(|entry| entry.deprecated)
+        {
+            lints.push(Lint::Deprecated {
+                found: s.to_owned(),
+            });
+        }
+        if let Some((true_text, false_text)) = self.canonical {
+            let canonical = if value { true_text } else { false_text };
+            if s != canonical {
+                lints.push(Lint::NonCanonical {
+                    found: s.to_owned(),
+                    canonical,
+                });
+            }
+        }
+        Ok((value, lints))
+    }
+
+    /// The parser used by `LenientBool` on the current thread: the one set by
+    /// `LenientBool::with_parser`, the one set by `set_default_parser`, or the default rules.
+    pub fn current() -> LenientBoolParser {
+        with_current(|p| p.cloned().unwrap_or_default())
+    }
+
+    /// The spellings to advertise as possible values, such as in a clap `PossibleValuesParser`.
+    ///
+    /// Deprecated spellings are left out, since they shouldn't be suggested to users. So is
+    /// the empty string, such as the empty cell that `spreadsheet()` accepts, which can't be
+    /// shown in a list.
+    pub fn possible_values(&self) -> impl Iterator<Item = &str> {
+        self.tokens
+            .iter()
+            .filter(|e| e.is_advertised())
+            .map(|e| &*e.text)
+    }
+
+    /// The advertised spellings that parse as `value`.
+    pub(crate) fn possible_values_for(&self, value: bool) -> impl Iterator<Item = &str> {
+        self.tokens
+            .iter()
+            .filter(move |e| e.value == value && e.is_advertised())
+            .map(|e| &*e.text)
+    }
+
+    /// Describes the accepted values for help output, such as `--help` text.
+    ///
+    /// Like `possible_values`, this leaves out deprecated spellings and the empty string.
+    ///
+    /// ```
+    /// use lenient_bool::{HelpStyle, LenientBoolParser};
+    ///
+    /// let parser = LenientBoolParser::dotnet();
+    /// assert_eq!(parser.help_text(HelpStyle::Flat), "true, false");
+    ///
+    /// let parser = LenientBoolParser::python().deprecate("t").deprecate("f");
+    /// let style = HelpStyle::Grouped { true_label: "ja", false_label: "nein" };
+    /// assert_eq!(parser.help_text(style), "ja: y, yes, true, on, 1; nein: n, no, false, off, 0");
+    /// ```
+    pub fn help_text(&self, style: HelpStyle) -> String {
+        let list = |value: bool| {
+            self.possible_values_for(value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match style {
+            HelpStyle::Flat => self.possible_values().collect::<Vec<_>>().join(", "),
+            HelpStyle::Grouped {
+                true_label,
+                false_label,
+            } => format!(
+                "{}: {}; {}: {}",
+                true_label,
+                list(true),
+                false_label,
+                list(false)
+            ),
+        }
+    }
+
+    /// The spellings that this parser accepts as `true`.
+    pub fn truthy_tokens(&self) -> impl Iterator<Item = &str> {
+        self.tokens.iter().filter(|e| e.value).map(|e| &*e.text)
+    }
+
+    /// The spellings that this parser accepts as `false`.
+    pub fn falsy_tokens(&self) -> impl Iterator<Item = &str> {
+        self.tokens.iter().filter(|e| !e.value).map(|e| &*e.text)
+    }
+}
+
+/// A `LenientBoolParser` with a hash index of its tokens, made by
+/// `LenientBoolParser::compile`. It parses the same values as the parser it was made from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledParser(LenientBoolParser);
+
+impl CompiledParser {
+    /// Parses `s` like `LenientBoolParser::parse`.
+    pub fn parse(&self, s: &str) -> Result<bool, LenientBoolError> {
+        self.0.parse(s)
+    }
+
+    /// Parses `s` like `LenientBoolParser::parse_with_lint`.
+    pub fn parse_with_lint(&self, s: &str) -> Result<(bool, Vec<Lint>), LenientBoolError> {
+        self.0.parse_with_lint(s)
+    }
+
+    /// Matches `s` like `LenientBoolParser::parse_fuzzy`. Fuzzy matching still compares the
+    /// input to every token.
+    pub fn parse_fuzzy(&self, s: &str) -> Result<FuzzyMatch<'_>, LenientBoolError> {
+        self.0.parse_fuzzy(s)
+    }
+
+    /// Returns the parser without its index, so it can be configured further.
+    pub fn into_parser(mut self) -> LenientBoolParser {
+        self.0.index = None;
+        self.0
+    }
+}
+
+impl BoolParser for LenientBoolParser {
+    fn parse(&self, s: &str) -> Result<bool, LenientBoolError> {
+        LenientBoolParser::parse(self, s)
+    }
+}
+
+impl BoolParser for CompiledParser {
+    fn parse(&self, s: &str) -> Result<bool, LenientBoolError> {
+        CompiledParser::parse(self, s)
+    }
+}
+
+impl Default for LenientBoolParser {
+    fn default() -> LenientBoolParser {
+        LenientBoolParser::new()
+    }
+}
+
+/// The configuration of a `LenientBoolParser`, for setting it up from a settings file so that
+/// the accepted spellings can be extended without recompiling.
+///
+/// With the `serde` feature, this implements `Deserialize` and `Serialize`. Missing fields
+/// take their default values, and unknown fields are rejected so that typos aren't silently
+/// ignored.
+///
+/// ```
+/// use lenient_bool::{LenientBoolConfig, LenientBoolParser};
+///
+/// let config = LenientBoolConfig {
+///     true_tokens: vec!["si".to_owned()],
+///     trim: true,
+///     ..LenientBoolConfig::default()
+/// };
+/// let parser = LenientBoolParser::from(config);
+/// assert_eq!(parser.parse(" SI "), Ok(true));
+/// assert_eq!(parser.parse("no"), Ok(false));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct LenientBoolConfig {
+    /// Whether to start from the `LenientBool` spellings. Defaults to `true`.
+    pub defaults: bool,
+    /// Spellings accepted as `true`, in addition to the defaults.
+    pub true_tokens: Vec<String>,
+    /// Spellings accepted as `false`, in addition to the defaults.
+    pub false_tokens: Vec<String>,
+    /// Whether to trim whitespace around values. Defaults to `false`.
+    pub trim: bool,
+    /// Whether to ignore case. Defaults to `true`.
+    pub case_insensitive: bool,
+}
+
+impl Default for LenientBoolConfig {
+    fn default() -> LenientBoolConfig {
+        LenientBoolConfig {
+            defaults: true,
+            true_tokens: Vec::new(),
+            false_tokens: Vec::new(),
+            trim: false,
+            case_insensitive: true,
+        }
+    }
+}
+
+impl From<LenientBoolConfig> for LenientBoolParser {
+    fn from(config: LenientBoolConfig) -> LenientBoolParser {
+        let mut parser = LenientBoolParser::new()
+            .trim(config.trim)
+            .case_insensitive(config.case_insensitive);
+        if !config.defaults {
+            parser.tokens.clear();
+        }
+        let entry = |value| {
+            move |text: String| Entry {
+                text: Cow::Owned(text),
+                value,
+                deprecated: false,
+                exact_case: false,
+            }
+        };
+        let true_tokens = config.true_tokens.into_iter().map(entry(true));
+        let false_tokens = config.false_tokens.into_iter().map(entry(false));
+        parser.tokens.extend(true_tokens.chain(false_tokens));
+        parser
+    }
+}
+
+/// Restores the previous thread-local parser when dropped, even if the closure panics.
+struct RestoreParser(Option<Rc<LenientBoolParser>>);
+
+impl Drop for RestoreParser {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Calls `f` with the parser set by `LenientBool::with_parser` on this thread, or else the one
+/// set by `set_default_parser`, if any.
+pub(crate) fn with_current<R>(f: impl FnOnce(Option<&LenientBoolParser>) -> R) -> R {
+    let parser = CURRENT.with(|current| current.borrow().clone());
+    f(parser.as_deref().or_else(|| DEFAULT.get()))
+}
+
+/// Replaces the `LenientBool` rules with `parser` for the whole process, so that every
+/// `str::parse::<LenientBool>()` and everything built on it, such as `#[derive(Deserialize)]`
+/// fields, uses it. Call this once at startup, before anything is parsed.
+///
+/// The default can only be set once. Later calls leave it unchanged and return
+/// `DefaultParserAlreadySet`.
+/// `LenientBool::with_parser` still overrides it for the duration of a closure.
+///
+/// ```
+/// use lenient_bool::{
+///     set_default_parser, DefaultParserAlreadySet, LenientBool, LenientBoolParser, TokenPack,
+/// };
+///
+/// let parser = LenientBoolParser::new().with_pack(TokenPack::ON_OFF);
+/// assert!(set_default_parser(parser).is_ok());
+/// assert_eq!("on".parse::<LenientBool>(), Ok(LenientBool(true)));
+/// assert_eq!(
+///     set_default_parser(LenientBoolParser::new()),
+///     Err(DefaultParserAlreadySet)
+/// );
+///
+/// // `with_parser` takes precedence.
+/// let go = LenientBool::with_parser(LenientBoolParser::go(), || "on".parse::<LenientBool>());
+/// assert!(go.is_err());
+/// ```
+pub fn set_default_parser(parser: LenientBoolParser) -> Result<(), DefaultParserAlreadySet> {
+    DEFAULT.set(parser).map_err(|_| DefaultParserAlreadySet)
+}
+
+/// The error returned by `set_default_parser` when a default parser has already been set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DefaultParserAlreadySet;
+
+impl fmt::Display for DefaultParserAlreadySet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the default boolean parser has already been set")
+    }
+}
+
+impl std::error::Error for DefaultParserAlreadySet {}
+
+impl LenientBool {
+    /// Runs `f` with `parser` replacing the `LenientBool` rules on the current thread.
+    ///
+    /// While `f` runs, `str::parse::<LenientBool>()` and everything built on it, such as the
+    /// serde and framework integrations, use `parser`. Other threads are unaffected, and the
+    /// previous rules are restored when `f` returns or panics. Calls can be nested.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBool, LenientBoolParser};
+    ///
+    /// let strict = LenientBool::with_parser(LenientBoolParser::go(), || "yes".parse::<LenientBool>());
+    /// assert!(strict.is_err());
+    /// assert_eq!("yes".parse::<LenientBool>(), Ok(LenientBool(true)));
+    /// ```
+    ///
+    /// This also overrides a parser set with `set_default_parser`.
+    pub fn with_parser<R>(parser: LenientBoolParser, f: impl FnOnce() -> R) -> R {
+        let previous = CURRENT.with(|current| current.replace(Some(Rc::new(parser))));
+        let _restore = RestoreParser(previous);
+        f()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn default_parse_yes() {
+        assert_eq!(LenientBoolParser::new().parse("YES"), Ok(true));
+    }
+
+    #[test]
+    fn default_parse_bad_input_err() {
+        assert_eq!(
+            LenientBoolParser::new().parse("abc"),
+            Err(LenientBoolError::new("abc"))
+        );
+    }
+
+    #[test]
+    fn custom_tokens() {
+        let parser = LenientBoolParser::new()
+            .true_tokens(vec![String::from("aktiv")])
+            .false_tokens(["inaktiv"])
+            .case_insensitive(false)
+            .trim(true);
+        assert_eq!(parser.parse(" aktiv\r\n"), Ok(true));
+        assert_eq!(parser.parse("inaktiv"), Ok(false));
+        assert!(parser.parse("AKTIV").is_err());
+        assert!(parser.parse("true").is_err());
+    }
+
+    #[test]
+    fn zero_padded() {
+        let parser = LenientBoolParser::new().numeric(NumericMode::ZeroPadded);
+        assert_eq!(parser.parse("01"), Ok(true));
+        assert_eq!(parser.parse("00"), Ok(false));
+        for s in ["", "10", "0x1", "-01", "+1"] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn nonzero() {
+        let parser = LenientBoolParser::new().numeric(NumericMode::Nonzero);
+        for s in ["2", "-1", "007", "+42", "123456789012345678901234567890"] {
+            assert_eq!(parser.parse(s), Ok(true), "{}", s);
+        }
+        for s in ["0", "000", "-0"] {
+            assert_eq!(parser.parse(s), Ok(false), "{}", s);
+        }
+        for s in ["", "-", "1.5", "1e3", "0x10"] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn decimal() {
+        let parser = LenientBoolParser::new().numeric(NumericMode::Decimal);
+        assert_eq!(parser.parse("1.0"), Ok(true));
+        assert_eq!(parser.parse("0.00"), Ok(false));
+        assert_eq!(parser.parse("-0.0"), Ok(false));
+        for s in ["0.5", "2.0", "inf", "NaN", "."] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn threshold() {
+        let parser = LenientBoolParser::new().numeric(NumericMode::Threshold(0.5));
+        assert_eq!(parser.parse("0.5"), Ok(true));
+        assert_eq!(parser.parse("0.49"), Ok(false));
+        assert_eq!(parser.parse("7"), Ok(true));
+        assert!(parser.parse("infinity").is_err());
+        assert_eq!(NumericMode::Threshold(0.5), NumericMode::Threshold(0.5));
+        assert_ne!(NumericMode::Threshold(0.5), NumericMode::Decimal);
+    }
+
+    #[test]
+    fn default_rejects_zero_padded() {
+        assert!(LenientBoolParser::new().parse("01").is_err());
+    }
+
+    #[test]
+    fn go_accepts_its_spellings() {
+        let parser = LenientBoolParser::go();
+        for s in ["1", "t", "T", "TRUE", "true", "True"] {
+            assert_eq!(parser.parse(s), Ok(true), "{}", s);
+        }
+        for s in ["0", "f", "F", "FALSE", "false", "False"] {
+            assert_eq!(parser.parse(s), Ok(false), "{}", s);
+        }
+    }
+
+    #[test]
+    fn yaml11_accepts_its_spellings() {
+        let parser = LenientBoolParser::yaml11();
+        for s in [
+            "y", "Y", "Yes", "YES", "true", "True", "TRUE", "on", "On", "ON",
+        ] {
+            assert_eq!(parser.parse(s), Ok(true), "{}", s);
+        }
+        for s in [
+            "n", "N", "no", "No", "NO", "false", "FALSE", "off", "Off", "OFF",
+        ] {
+            assert_eq!(parser.parse(s), Ok(false), "{}", s);
+        }
+    }
+
+    #[test]
+    fn yaml11_rejects_other_spellings() {
+        let parser = LenientBoolParser::yaml11();
+        for s in ["yEs", "oN", "1", "0", "t", "f", " yes", ""] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn spreadsheet_accepts_its_spellings() {
+        let parser = LenientBoolParser::spreadsheet();
+        for s in ["TRUE", "true", "True()", "X", "x", "✓", "✔", " 1 "] {
+            assert_eq!(parser.parse(s), Ok(true), "{}", s);
+        }
+        for s in ["FALSE", "false()", "0", "", "   ", "\t"] {
+            assert_eq!(parser.parse(s), Ok(false), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn spreadsheet_rejects_other_spellings() {
+        let parser = LenientBoolParser::spreadsheet();
+        for s in ["yes", "t", "TRUE ()", "xx", "2", "1.0", "(TRUE)"] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn spreadsheet_help_text() {
+        let parser = LenientBoolParser::spreadsheet();
+        assert_eq!(
+            parser.help_text(HelpStyle::Flat),
+            "TRUE, TRUE(), X, ✓, ✔, 1, FALSE, FALSE(), 0"
+        );
+        assert!(parser.possible_values().all(|t| !t.is_empty()));
+    }
+
+    #[test]
+    fn spreadsheet_compiled() {
+        let parser = LenientBoolParser::spreadsheet().compile();
+        assert_eq!(parser.parse("true()"), Ok(true));
+        assert_eq!(parser.parse(" "), Ok(false));
+    }
+
+    #[test]
+    fn go_rejects_other_spellings() {
+        let parser = LenientBoolParser::go();
+        for s in ["tRUE", "yes", "y", "no", " true", ""] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn python_accepts_on_off() {
+        let parser = LenientBoolParser::python();
+        assert_eq!(parser.parse("On"), Ok(true));
+        assert_eq!(parser.parse("OFF"), Ok(false));
+    }
+
+    #[test]
+    fn python_rejects_other_spellings() {
+        let parser = LenientBoolParser::python();
+        for s in ["enabled", "2", " yes", ""] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+        // `strtobool` only lowercases, so `ſ` isn't `s` as it is for `LenientBool`.
+        assert!(parser.parse("YEſ").is_err());
+    }
+
+    #[test]
+    fn dotnet_trims() {
+        let parser = LenientBoolParser::dotnet();
+        assert_eq!(parser.parse("  True\t"), Ok(true));
+        assert_eq!(parser.parse("FALSE\0"), Ok(false));
+    }
+
+    #[test]
+    fn dotnet_rejects_other_spellings() {
+        let parser = LenientBoolParser::dotnet();
+        for s in ["1", "yes", "t", ""] {
+            assert!(parser.parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn postgres_prefixes() {
+        let parser = LenientBoolParser::postgres();
+        for s in ["t", "TR", "tru", "True", "y", "yE", "YES", "on", "1"] {
+            assert_eq!(parser.parse(s), Ok(true), "{}", s);
+        }
+        for s in [
+            "f",
+            "FAL",
+            "fals",
+            "n",
+            "NO",
+            "of",
+            "Off",
+            "0",
+            "\x0b no\x0c",
+        ] {
+            assert_eq!(parser.parse(s), Ok(false), "{}", s);
+        }
+    }
+
+    #[test]
+    fn postgres_rejects_other_spellings() {
+        let parser = LenientBoolParser::postgres();
+        for s in ["o", "truee", "", "00", "\u{a0}true", "yeſ", "onn", "true\0"] {
+            assert!(parser.parse(s).is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "tokens-numeric",
+        feature = "tokens-single-letter",
+        feature = "tokens-yes-no"
+    ))]
+    fn strip_quotes() {
+        let parser = LenientBoolParser::new().strip_quotes(true).trim(true);
+        assert_eq!(parser.parse(" 'Y' "), Ok(true));
+        assert_eq!(parser.parse("\"0\""), Ok(false));
+        assert_eq!(parser.parse("yes"), Ok(true));
+    }
+
+    #[test]
+    fn strip_quotes_one_layer() {
+        let parser = LenientBoolParser::new().strip_quotes(true);
+        for s in ["\"'true'\"", "\"", "'", "''", "\"true", "' true'"] {
+            assert!(parser.parse(s).is_err(), "{:?}", s);
+        }
+        assert!(LenientBoolParser::new().parse("'true'").is_err());
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "tokens-numeric",
+        feature = "tokens-single-letter",
+        feature = "tokens-yes-no"
+    ))]
+    fn trailing_punctuation() {
+        let parser = LenientBoolParser::new().trailing_punctuation(true);
+        for (s, value) in [
+            ("true;", true),
+            ("no,", false),
+            ("Y?!", true),
+            ("0 .", false),
+        ] {
+            assert_eq!(parser.parse(s), Ok(value), "{:?}", s);
+        }
+        for s in ["yes-", "?", "ye.s", "yes.x"] {
+            assert!(parser.parse(s).is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn trailing_punctuation_with_quotes() {
+        let parser = LenientBoolParser::new()
+            .trailing_punctuation(true)
+            .strip_quotes(true);
+        assert_eq!(parser.parse("\"yes\"."), Ok(true));
+    }
+
+    #[test]
+    fn edit_distances() {
+        let distance = |a: &str, b: &str, max: usize| {
+            edit_distance(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+                max,
+            )
+        };
+        assert_eq!(distance("true", "true", 5), Some(0));
+        assert_eq!(distance("ture", "true", 5), Some(1));
+        assert_eq!(distance("tru", "true", 5), Some(1));
+        assert_eq!(distance("truee", "true", 5), Some(1));
+        assert_eq!(distance("flsae", "false", 5), Some(2));
+        assert_eq!(distance("", "no", 5), Some(2));
+    }
+
+    #[test]
+    fn edit_distances_bounded() {
+        let distance = |a: &str, b: &str, max: usize| {
+            edit_distance(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+                max,
+            )
+        };
+        assert_eq!(distance("flsae", "false", 1), None);
+        assert_eq!(distance("flsae", "false", 2), Some(2));
+        assert_eq!(distance("falsefalse", "false", 4), None);
+        assert_eq!(distance("xxxxe", "false", 3), None);
+    }
+
+    #[test]
+    fn long_junk_is_fast() {
+        let synonyms = (0..2000).map(|i| format!("synonym-{}", i));
+        let junk = "x".repeat(200_000);
+        let parsers = [
+            LenientBoolParser::new().true_tokens(synonyms.clone()),
+            LenientBoolParser::new().true_tokens(synonyms).fuzzy(2),
+        ];
+        let start = std::time::Instant::now();
+        for parser in parsers {
+            let compiled = parser.clone().compile();
+            for _ in 0..10 {
+                assert!(parser.parse(&junk).is_err());
+                assert!(compiled.parse(&junk).is_err());
+            }
+        }
+        // Scanning every token with an unbounded distance takes minutes here.
+        assert!(start.elapsed().as_secs() < 10, "{:?}", start.elapsed());
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn fuzzy() {
+        let parser = LenientBoolParser::new().fuzzy(1);
+        for (s, value) in [
+            ("ture", true),
+            ("Flase", false),
+            ("yse", true),
+            ("noo", false),
+        ] {
+            assert_eq!(parser.parse(s), Ok(value), "{:?}", s);
+        }
+        // `a` is one edit from `y` and `n`, but single-character tokens don't match fuzzily.
+        for s in ["a", "maybe", "flsae"] {
+            assert!(parser.parse(s).is_err(), "{:?}", s);
+        }
+        assert!(LenientBoolParser::new().parse("ture").is_err());
+    }
+
+    #[test]
+    fn fuzzy_short_input() {
+        // `a` is one edit from `ja`, but single-character input doesn't match fuzzily either.
+        let parser = LenientBoolParser::new().true_tokens(["ja"]).fuzzy(1);
+        assert!(parser.parse("a").is_err());
+        assert_eq!(parser.parse("jaa"), Ok(true));
+    }
+
+    #[test]
+    fn fuzzy_folds_like_exact_matches() {
+        // `ſ` folds to `s`, so this is `fasle`, one swap from `false`.
+        let parser = LenientBoolParser::new().fuzzy(1);
+        assert_eq!(parser.parse("FAſLE"), Ok(false));
+    }
+
+    #[test]
+    fn fuzzy_ambiguous() {
+        let parser = LenientBoolParser::new()
+            .true_tokens(["abcd"])
+            .false_tokens(["abce"])
+            .fuzzy(1);
+        assert!(parser.parse("abcf").is_err());
+        assert_eq!(parser.parse("abd"), Ok(true));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn parse_fuzzy_reports_token() {
+        let parser = LenientBoolParser::new().fuzzy(1);
+        assert_eq!(
+            parser.parse_fuzzy("YES"),
+            Ok(FuzzyMatch {
+                value: true,
+                token: "yes",
+                distance: 0
+            })
+        );
+        assert_eq!(parser.parse_fuzzy("ture").map(|m| m.token), Ok("true"));
+        assert!(parser.parse_fuzzy("2").is_err());
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "tokens-numeric",
+        feature = "tokens-single-letter",
+        feature = "tokens-yes-no"
+    ))]
+    fn negation() {
+        let parser = LenientBoolParser::new().negation(true);
+        assert_eq!(parser.parse("!yes"), Ok(false));
+        assert_eq!(parser.parse("NOT false"), Ok(true));
+        assert_eq!(parser.parse("not  1"), Ok(false));
+        assert_eq!(parser.parse("!!t"), Ok(true));
+        assert_eq!(parser.parse("no n"), Ok(true));
+    }
+
+    #[test]
+    fn negation_bad_input_err() {
+        let parser = LenientBoolParser::new().negation(true);
+        for s in ["!", "not", "not ", "nottrue", "not maybe", "true!"] {
+            assert!(parser.parse(s).is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn negation_needs_a_token() {
+        let parser = LenientBoolParser::spreadsheet().negation(true);
+        assert_eq!(parser.parse(""), Ok(false));
+        for s in ["!", "!!", "not", "! "] {
+            assert!(parser.parse(s).is_err(), "{:?}", s);
+        }
+        assert_eq!(parser.parse("!TRUE"), Ok(false));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn negation_long_chain() {
+        let parser = LenientBoolParser::new().negation(true).fuzzy(1);
+        let chain = "!".repeat(1_000_000);
+        assert_eq!(parser.parse(&format!("{}yes", chain)), Ok(true));
+        assert_eq!(parser.parse(&format!("!{}ture", chain)), Ok(false));
+        assert!(parser.parse(&chain).is_err());
+    }
+
+    #[test]
+    fn negation_off_by_default() {
+        assert!(LenientBoolParser::new().parse("!yes").is_err());
+    }
+
+    #[test]
+    fn java_true() {
+        assert_eq!(LenientBoolParser::java().parse("tRuE"), Ok(true));
+    }
+
+    #[test]
+    fn java_never_fails() {
+        let parser = LenientBoolParser::java();
+        for s in ["false", "yes", "1", " true", ""] {
+            assert_eq!(parser.parse(s), Ok(false), "{}", s);
+        }
+    }
+
+    #[test]
+    fn kubernetes_canonical() {
+        let parser = LenientBoolParser::kubernetes();
+        assert_eq!(parser.parse_with_lint("false"), Ok((false, vec![])));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-numeric")]
+    fn kubernetes_non_canonical() {
+        let parser = LenientBoolParser::kubernetes();
+        assert_eq!(
+            parser.parse_with_lint("1"),
+            Ok((
+                true,
+                vec![Lint::NonCanonical {
+                    found: "1".to_owned(),
+                    canonical: "true"
+                }]
+            ))
+        );
+    }
+
+    #[test]
+    fn kubernetes_bad_input_err() {
+        assert!(LenientBoolParser::kubernetes()
+            .parse_with_lint("abc")
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-yes-no")]
+    fn deprecated_and_non_canonical() {
+        let parser = LenientBoolParser::kubernetes().deprecate("yes");
+        let (value, lints) = parser.parse_with_lint("YES").unwrap();
+        assert!(value);
+        assert_eq!(lints.len(), 2);
+        assert_eq!(lints[0].to_string(), r#"deprecated boolean value "YES""#);
+    }
+
+    #[test]
+    fn deprecate_case_sensitive() {
+        let parser = LenientBoolParser::go().deprecate("TRUE");
+        assert_eq!(parser.parse_with_lint("true"), Ok((true, vec![])));
+        assert_eq!(parser.parse_with_lint("TRUE").unwrap().1.len(), 1);
+    }
+
+    #[test]
+    fn deprecate_pack_token() {
+        let parser = LenientBoolParser::new()
+            .with_pack(TokenPack::excel("fr").unwrap())
+            .deprecate("faux");
+        assert_eq!(parser.parse_with_lint("FAUX").unwrap().1.len(), 1);
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokens-single-letter", feature = "tokens-yes-no"))]
+    fn exact_case() {
+        let parser = LenientBoolParser::new().exact_case(["TRUE", "false"]);
+        assert_eq!(parser.parse("true"), Ok(true));
+        assert!(parser.parse("TRUE").is_err());
+        assert!(parser.parse("False").is_err());
+        assert_eq!(parser.parse("YES"), Ok(true));
+        assert_eq!(parser.parse("N"), Ok(false));
+    }
+
+    #[test]
+    fn exact_case_compiled() {
+        let parser = LenientBoolParser::new()
+            .true_tokens(["on", "On"])
+            .exact_case(["On"])
+            .compile();
+        assert_eq!(parser.parse("on"), Ok(true));
+        assert!(parser.parse("ON").is_err());
+    }
+
+    #[test]
+    fn exact_case_fuzzy() {
+        let parser = LenientBoolParser::new().exact_case(["true"]).fuzzy(1);
+        assert_eq!(parser.parse("ture"), Ok(true));
+        assert!(parser.parse("TRUE").is_err());
+    }
+
+    #[test]
+    fn help_text_flat() {
+        let expected: Vec<&str> = TRUE_TOKENS.iter().chain(FALSE_TOKENS).copied().collect();
+        assert_eq!(
+            LenientBoolParser::new().help_text(HelpStyle::Flat),
+            expected.join(", ")
+        );
+    }
+
+    #[test]
+    fn help_text_grouped() {
+        let style = HelpStyle::Grouped {
+            true_label: "on",
+            false_label: "off",
+        };
+        assert_eq!(
+            LenientBoolParser::dotnet().help_text(style),
+            "on: true; off: false"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn possible_values_skip_deprecated() {
+        let parser = LenientBoolParser::new().deprecate("t").deprecate("f");
+        assert!(!parser.possible_values().any(|t| t == "t" || t == "f"));
+        assert_eq!(
+            parser.possible_values().count(),
+            TRUE_TOKENS.len() + FALSE_TOKENS.len() - 2
+        );
+    }
+
+    #[test]
+    fn current_follows_override() {
+        LenientBool::with_parser(LenientBoolParser::dotnet(), || {
+            assert_eq!(LenientBoolParser::current(), LenientBoolParser::dotnet());
+        });
+        assert_eq!(LenientBoolParser::current(), LenientBoolParser::new());
+    }
+
+    #[test]
+    fn pack_non_ascii_case() {
+        let parser = LenientBoolParser::new().with_pack(TokenPack::excel("pl").unwrap());
+        assert_eq!(parser.parse("fałsz"), Ok(false));
+        assert_eq!(parser.parse("Prawda"), Ok(true));
+    }
+
+    #[test]
+    fn pack_case_sensitive() {
+        let parser = LenientBoolParser::go().with_pack(TokenPack::excel("de").unwrap());
+        assert_eq!(parser.parse("WAHR"), Ok(true));
+        assert!(parser.parse("wahr").is_err());
+    }
+
+    #[test]
+    fn with_parser_overrides() {
+        let result =
+            LenientBool::with_parser(LenientBoolParser::python(), || "off".parse::<LenientBool>());
+        assert_eq!(result, Ok(LenientBool(false)));
+        assert!("off".parse::<LenientBool>().is_err());
+    }
+
+    #[test]
+    fn with_parser_nested() {
+        LenientBool::with_parser(LenientBoolParser::python(), || {
+            LenientBool::with_parser(LenientBoolParser::go(), || {
+                assert!("on".parse::<LenientBool>().is_err());
+            });
+            assert_eq!("on".parse::<LenientBool>(), Ok(LenientBool(true)));
+        });
+    }
+
+    #[test]
+    fn config_default() {
+        let parser = LenientBoolParser::from(LenientBoolConfig::default());
+        assert_eq!(parser, LenientBoolParser::new());
+    }
+
+    #[test]
+    fn config_without_defaults() {
+        let parser = LenientBoolParser::from(LenientBoolConfig {
+            defaults: false,
+            true_tokens: vec!["Aktiv".to_owned()],
+            false_tokens: vec!["Inaktiv".to_owned()],
+            case_insensitive: false,
+            ..LenientBoolConfig::default()
+        });
+        assert_eq!(parser.parse("Aktiv"), Ok(true));
+        assert_eq!(parser.parse("Inaktiv"), Ok(false));
+        assert!(parser.parse("aktiv").is_err());
+        assert!(parser.parse("yes").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "nfkc")]
+    fn nfkc_matches_from_str() {
+        let parser = LenientBoolParser::new();
+        for s in ["ｙｅｓ", "①", "⁰", "ＦＡＬＳＥ", "ｍａｙｂｅ"] {
+            let expected = s.parse::<LenientBool>().map(|b| b.0);
+            assert_eq!(parser.parse(s), expected, "{}", s);
+            let with_parser = LenientBool::with_parser(parser.clone(), || s.parse::<LenientBool>());
+            assert_eq!(with_parser.map(|b| b.0), expected, "{}", s);
+        }
+        let parser = LenientBoolParser::new().strip_quotes(true);
+        assert_eq!(parser.parse("＂ｎｏ＂"), Ok(false));
+        assert_eq!(
+            parser.parse("ｍａｙｂｅ").unwrap_err().input(),
+            "ｍａｙｂｅ"
+        );
+        // Profiles that match other parsers byte for byte don't normalize.
+        assert!(LenientBoolParser::python().parse("ｙｅｓ").is_err());
+        assert!(LenientBoolParser::go().parse("ｔｒｕｅ").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn config_deserialize() {
+        let config: LenientBoolConfig =
+            serde_json::from_str(r#"{ "true_tokens": ["yep"], "trim": true }"#).unwrap();
+        let parser = LenientBoolParser::from(config);
+        assert_eq!(parser.parse(" YEP"), Ok(true));
+        assert_eq!(parser.parse("1"), Ok(true));
+
+        let err = serde_json::from_str::<LenientBoolConfig>(r#"{ "true_token": ["yep"] }"#);
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn with_parser_restores_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            LenientBool::with_parser(LenientBoolParser::java(), || panic!("oops"))
+        });
+        assert!(result.is_err());
+        assert!("abc".parse::<LenientBool>().is_err());
+    }
+
+    #[test]
+    fn with_parser_other_threads_unaffected() {
+        LenientBool::with_parser(LenientBoolParser::java(), || {
+            let other = std::thread::spawn(|| "abc".parse::<LenientBool>().is_err());
+            assert!(other.join().unwrap());
+        });
+    }
+
+    #[test]
+    fn compiled_agrees() {
+        let many = (0..200).map(|i| format!("Word{}", i));
+        let parsers = [
+            LenientBoolParser::new(),
+            LenientBoolParser::go(),
+            LenientBoolParser::postgres(),
+            LenientBoolParser::new().case_insensitive(false),
+            LenientBoolParser::new()
+                .with_pack(TokenPack::ON_OFF)
+                .true_tokens(many)
+                .negation(true)
+                .trim(true),
+        ];
+        let inputs = [
+            "true",
+            "TRUE",
+            "True",
+            "yes",
+            "Y",
+            "0",
+            "off",
+            "word7",
+            "WORD199",
+            "word200",
+            " not word3 ",
+            "\u{130}",
+            "ǅ",
+            "",
+            "abc",
+        ];
+        for parser in parsers {
+            let compiled = parser.clone().compile();
+            for s in inputs {
+                assert_eq!(compiled.parse(s), parser.parse(s), "{:?} {:?}", parser, s);
+            }
+            assert_eq!(compiled.into_parser(), parser);
+        }
+    }
+
+    #[test]
+    fn compiled_first_token_wins() {
+        let parser = LenientBoolParser::new()
+            .true_tokens(["same"])
+            .false_tokens(["SAME"])
+            .compile();
+        assert_eq!(parser.parse("same"), Ok(true));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn compiled_lints() {
+        let parser = LenientBoolParser::new().deprecate("y").compile();
+        assert_eq!(
+            parser.parse_with_lint("Y"),
+            Ok((
+                true,
+                vec![Lint::Deprecated {
+                    found: "Y".to_owned()
+                }]
+            ))
+        );
+    }
+
+    #[test]
+    fn tokens() {
+        let parser = LenientBoolParser::go();
+        assert_eq!(parser.truthy_tokens().count(), 6);
+        assert!(parser.falsy_tokens().any(|t| t == "False"));
+    }
+}
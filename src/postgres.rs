@@ -0,0 +1,139 @@
+//! Integration with `postgres-types`, as used by `postgres` and `tokio-postgres`.
+
+use std::error::Error;
+
+use ::bytes::BytesMut;
+use ::postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use super::LenientBool;
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+fn is_text(ty: &Type) -> bool {
+    matches!(*ty, Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME)
+}
+
+/// Reads a `LenientBool` from a BOOL, INT2, INT4, or text column.
+///
+/// Integers are `false` when zero and `true` otherwise. Text is parsed with the `LenientBool`
+/// rules, ignoring the trailing padding of `CHAR(n)` columns.
+impl<'a> FromSql<'a> for LenientBool {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        match *ty {
+            Type::BOOL => bool::from_sql(ty, raw).map(LenientBool),
+            Type::INT2 => i16::from_sql(ty, raw).map(|i| LenientBool(i != 0)),
+            Type::INT4 => i32::from_sql(ty, raw).map(|i| LenientBool(i != 0)),
+            _ => {
+                let s = <&str>::from_sql(ty, raw)?;
+                let s = if *ty == Type::BPCHAR { s.trim_end() } else { s };
+                s.parse().map_err(|e| Box::new(e) as _)
+            }
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BOOL | Type::INT2 | Type::INT4) || is_text(ty)
+    }
+}
+
+/// Writes a `LenientBool` to a BOOL, INT2, INT4, or text column.
+///
+/// Integers are written as `0` or `1`, and text as `true` or `false`.
+impl ToSql for LenientBool {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        match *ty {
+            Type::BOOL => self.0.to_sql(ty, out),
+            Type::INT2 => i16::from(self.0).to_sql(ty, out),
+            Type::INT4 => i32::from(self.0).to_sql(ty, out),
+            _ => (if self.0 { "true" } else { "false" }).to_sql(ty, out),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <LenientBool as FromSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::LenientBoolError;
+
+    fn from_sql(ty: Type, raw: &[u8]) -> Option<LenientBool> {
+        LenientBool::from_sql(&ty, raw).ok()
+    }
+
+    fn to_sql(value: bool, ty: Type) -> Vec<u8> {
+        let mut out = BytesMut::new();
+        LenientBool(value).to_sql_checked(&ty, &mut out).unwrap();
+        out.to_vec()
+    }
+
+    #[test]
+    fn from_bool() {
+        assert_eq!(from_sql(Type::BOOL, &[1]), Some(LenientBool(true)));
+        assert_eq!(from_sql(Type::BOOL, &[0]), Some(LenientBool(false)));
+    }
+
+    #[test]
+    fn from_int2() {
+        assert_eq!(from_sql(Type::INT2, &[0, 1]), Some(LenientBool(true)));
+        assert_eq!(from_sql(Type::INT2, &[0, 0]), Some(LenientBool(false)));
+    }
+
+    #[test]
+    fn from_int4() {
+        assert_eq!(from_sql(Type::INT4, &[0, 0, 0, 2]), Some(LenientBool(true)));
+        assert_eq!(
+            from_sql(Type::INT4, &[0, 0, 0, 0]),
+            Some(LenientBool(false))
+        );
+    }
+
+    #[test]
+    fn from_text() {
+        assert_eq!(from_sql(Type::TEXT, b"yes"), Some(LenientBool(true)));
+        assert_eq!(from_sql(Type::VARCHAR, b"N"), Some(LenientBool(false)));
+    }
+
+    #[test]
+    fn from_bpchar_padded() {
+        assert_eq!(from_sql(Type::BPCHAR, b"Y   "), Some(LenientBool(true)));
+    }
+
+    #[test]
+    fn from_text_bad_input_err() {
+        assert_eq!(from_sql(Type::TEXT, b"abc"), None);
+    }
+
+    #[test]
+    fn from_text_err_is_lenient_bool_error() {
+        let err = <LenientBool as FromSql>::from_sql(&Type::TEXT, b"treu").unwrap_err();
+        let err = err.downcast::<LenientBoolError>().unwrap();
+        assert_eq!(err.suggestion(), Some("true"));
+    }
+
+    #[test]
+    fn accepts() {
+        assert!(<LenientBool as FromSql>::accepts(&Type::INT2));
+        assert!(!<LenientBool as FromSql>::accepts(&Type::INT8));
+        assert!(!<LenientBool as FromSql>::accepts(&Type::FLOAT4));
+    }
+
+    #[test]
+    fn to_bool() {
+        assert_eq!(to_sql(true, Type::BOOL), vec![1]);
+    }
+
+    #[test]
+    fn to_int4() {
+        assert_eq!(to_sql(true, Type::INT4), vec![0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn to_text() {
+        assert_eq!(to_sql(false, Type::TEXT), b"false".to_vec());
+    }
+}
@@ -0,0 +1,80 @@
+//! Integration with `proptest`, for property tests of code that parses lenient booleans.
+//!
+//! `LenientBool` implements `proptest::arbitrary::Arbitrary`, and `valid_token` generates
+//! every accepted spelling, in random case, paired with the value it parses to.
+//!
+//! ```
+//! use lenient_bool::proptest::valid_token;
+//! use lenient_bool::LenientBool;
+//! use proptest::strategy::{Strategy, ValueTree};
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::default();
+//! let (text, value) = valid_token().new_tree(&mut runner).unwrap().current();
+//! assert_eq!(text.parse(), Ok(LenientBool(value)));
+//! ```
+
+use ::proptest::arbitrary::{any, Arbitrary};
+use ::proptest::bool;
+use ::proptest::collection::vec;
+use ::proptest::sample::select;
+use ::proptest::strategy::{Map, Strategy};
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+impl Arbitrary for LenientBool {
+    type Parameters = ();
+    type Strategy = Map<bool::Any, fn(bool) -> LenientBool>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        bool::ANY.prop_map(LenientBool)
+    }
+}
+
+/// A strategy for the default tokens, with the case of each character chosen at random,
+/// along with the value that each one parses to.
+pub fn valid_token() -> impl Strategy<Value = (String, bool)> {
+    let tokens: Vec<(&'static str, bool)> = TRUE_TOKENS
+        .iter()
+        .map(|t| (*t, true))
+        .chain(FALSE_TOKENS.iter().map(|t| (*t, false)))
+        .collect();
+    select(tokens).prop_flat_map(|(token, value)| {
+        vec(any::<bool>(), token.chars().count()).prop_map(move |upper| {
+            let mut text = String::with_capacity(token.len());
+            for (c, upper) in token.chars().zip(upper) {
+                if upper {
+                    text.extend(c.to_uppercase());
+                } else {
+                    text.push(c);
+                }
+            }
+            (text, value)
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use ::proptest::prelude::*;
+
+    use super::*;
+    use crate::invariants;
+
+    proptest! {
+        #[test]
+        fn valid_tokens_parse((text, value) in valid_token()) {
+            prop_assert_eq!(text.parse(), Ok(LenientBool(value)));
+        }
+
+        #[test]
+        fn arbitrary_round_trips(b in any::<LenientBool>()) {
+            prop_assert_eq!(b.as_str().parse(), Ok(b));
+        }
+
+        #[test]
+        fn invariants_hold(s in ".{0,8}") {
+            invariants::check_str(&s);
+        }
+    }
+}
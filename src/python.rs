@@ -0,0 +1,86 @@
+//! A Python extension module built with PyO3.
+//!
+//! The module is named `lenient_bool` and provides:
+//!
+//! ```text
+//! parse(s: str) -> bool            # raises ValueError for unrecognized input
+//! try_parse(s: str) -> bool | None
+//! try_parse_many(values: list[str | None]) -> list[bool | None]
+//! ```
+//!
+//! `try_parse_many` converts a whole column in one call, which is much faster than calling
+//! `try_parse` once per row, for example with `df["flag"] = try_parse_many(df["flag"].tolist())`.
+//!
+//! To build it, compile the crate as a `cdylib` with the `python` feature and PyO3's
+//! `extension-module` feature, for example with maturin:
+//!
+//! ```text
+//! maturin build --features python,pyo3/extension-module
+//! ```
+
+use ::pyo3::exceptions::PyValueError;
+use ::pyo3::prelude::*;
+
+use super::LenientBool;
+
+/// Parses `s` with the `LenientBool` rules, raising `ValueError` if it isn't recognized.
+#[pyfunction]
+pub fn parse(s: &str) -> PyResult<bool> {
+    s.parse::<LenientBool>()
+        .map(bool::from)
+        .map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Parses `s` with the `LenientBool` rules, returning `None` if it isn't recognized.
+#[pyfunction]
+pub fn try_parse(s: &str) -> Option<bool> {
+    s.parse::<LenientBool>().ok().map(bool::from)
+}
+
+/// Parses each of `values` with `try_parse`, passing `None` through.
+#[pyfunction]
+pub fn try_parse_many(values: Vec<Option<String>>) -> Vec<Option<bool>> {
+    values
+        .iter()
+        .map(|value| value.as_deref().and_then(try_parse))
+        .collect()
+}
+
+#[pymodule]
+fn lenient_bool(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(try_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(try_parse_many, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_yes() {
+        assert!(parse("yes").unwrap());
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        assert!(parse("abc").is_err());
+    }
+
+    #[test]
+    fn try_parse_f() {
+        assert_eq!(try_parse("F"), Some(false));
+    }
+
+    #[test]
+    fn try_parse_bad_input() {
+        assert_eq!(try_parse("abc"), None);
+    }
+
+    #[test]
+    fn try_parse_many_values() {
+        let values = vec![Some("Y".to_owned()), None, Some("abc".to_owned())];
+        assert_eq!(try_parse_many(values), [Some(true), None, None]);
+    }
+}
@@ -0,0 +1,183 @@
+//! Parsing XML attribute values and text nodes read with `quick-xml`.
+//!
+//! Values can be parsed either as `xs:boolean`, which only accepts `true`, `false`, `1`, and
+//! `0` exactly, or with the `LenientBool` rules. In both cases surrounding XML whitespace is
+//! ignored, as the `xs:boolean` whitespace rules require.
+//!
+//! The helpers work directly on the raw bytes, and only allocate when a value contains an
+//! entity reference that has to be unescaped.
+//!
+//! ```
+//! use lenient_bool::quick_xml::{parse_attribute, Profile};
+//! use quick_xml::events::Event;
+//! use quick_xml::Reader;
+//!
+//! let mut reader = Reader::from_str(r#"<feature enabled="Yes" beta="1"/>"#);
+//! let Ok(Event::Empty(element)) = reader.read_event() else {
+//!     panic!("expected an element");
+//! };
+//!
+//! let enabled = element.try_get_attribute("enabled").unwrap().unwrap();
+//! assert_eq!(parse_attribute(&enabled, Profile::Lenient), Ok(true));
+//! assert!(parse_attribute(&enabled, Profile::XsBoolean).is_err());
+//!
+//! let beta = element.try_get_attribute("beta").unwrap().unwrap();
+//! assert_eq!(parse_attribute(&beta, Profile::XsBoolean), Ok(true));
+//! ```
+
+use std::error;
+use std::fmt;
+use std::str;
+
+use ::quick_xml::escape::{unescape, EscapeError};
+use ::quick_xml::events::attributes::Attribute;
+use ::quick_xml::events::BytesText;
+
+use super::LenientBool;
+
+/// Which spellings to accept.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Profile {
+    /// The XML Schema `xs:boolean` lexical space: `true`, `false`, `1`, and `0`, case-sensitive.
+    XsBoolean,
+    /// The values accepted by `LenientBool`.
+    #[default]
+    Lenient,
+}
+
+/// An error from parsing an XML value.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Error {
+    /// The value contained an invalid entity reference.
+    Escape(EscapeError),
+    /// The value could not be parsed as a boolean.
+    InvalidValue(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Escape(e) => e.fmt(f),
+            Error::InvalidValue(value) => write!(f, "invalid boolean value {:?}", value),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Escape(e) => Some(e),
+            Error::InvalidValue(_) => None,
+        }
+    }
+}
+
+impl From<EscapeError> for Error {
+    fn from(e: EscapeError) -> Error {
+        Error::Escape(e)
+    }
+}
+
+fn is_xml_whitespace(b: &u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\r' | b'\n')
+}
+
+fn parse_unescaped(value: &[u8], profile: Profile) -> Option<bool> {
+    let start = value.iter().position(|b| !is_xml_whitespace(b))?;
+    let end = value.iter().rposition(|b| !is_xml_whitespace(b))? + 1;
+    let value = &value[start..end];
+    match profile {
+        Profile::XsBoolean => match value {
+            b"true" | b"1" => Some(true),
+            b"false" | b"0" => Some(false),
+            _ => None,
+        },
+        Profile::Lenient => str::from_utf8(value)
+            .ok()
+            .and_then(|s| s.parse::<LenientBool>().ok())
+            .map(bool::from),
+    }
+}
+
+/// Parses a raw, still-escaped attribute value or text node.
+pub fn parse_bytes(value: &[u8], profile: Profile) -> Result<bool, Error> {
+    let parsed = if value.contains(&b'&') {
+        let s = str::from_utf8(value)
+            .map_err(|_| Error::InvalidValue(String::from_utf8_lossy(value).into_owned()))?;
+        parse_unescaped(unescape(s)?.as_bytes(), profile)
+    } else {
+        parse_unescaped(value, profile)
+    };
+    parsed.ok_or_else(|| Error::InvalidValue(String::from_utf8_lossy(value).into_owned()))
+}
+
+/// Parses the value of an attribute.
+pub fn parse_attribute(attribute: &Attribute, profile: Profile) -> Result<bool, Error> {
+    parse_bytes(&attribute.value, profile)
+}
+
+/// Parses the content of a text node.
+pub fn parse_text(text: &BytesText, profile: Profile) -> Result<bool, Error> {
+    parse_bytes(text, profile)
+}
+
+#[cfg(test)]
+mod test {
+    use ::quick_xml::events::Event;
+    use ::quick_xml::Reader;
+
+    use super::*;
+
+    #[test]
+    fn xs_boolean_values() {
+        assert_eq!(parse_bytes(b"true", Profile::XsBoolean), Ok(true));
+        assert_eq!(parse_bytes(b"0", Profile::XsBoolean), Ok(false));
+    }
+
+    #[test]
+    fn xs_boolean_is_strict() {
+        assert_eq!(
+            parse_bytes(b"TRUE", Profile::XsBoolean),
+            Err(Error::InvalidValue("TRUE".to_owned()))
+        );
+    }
+
+    #[test]
+    fn lenient_values() {
+        assert_eq!(parse_bytes(b"Y", Profile::Lenient), Ok(true));
+        assert_eq!(parse_bytes(b"no", Profile::Lenient), Ok(false));
+    }
+
+    #[test]
+    fn whitespace_is_ignored() {
+        assert_eq!(parse_bytes(b"\n  false\t", Profile::XsBoolean), Ok(false));
+    }
+
+    #[test]
+    fn entity_is_unescaped() {
+        assert_eq!(parse_bytes(b"&#49;", Profile::XsBoolean), Ok(true));
+    }
+
+    #[test]
+    fn bad_entity_err() {
+        assert!(matches!(
+            parse_bytes(b"&bogus;", Profile::Lenient),
+            Err(Error::Escape(_))
+        ));
+    }
+
+    #[test]
+    fn empty_err() {
+        assert!(parse_bytes(b"  ", Profile::Lenient).is_err());
+    }
+
+    #[test]
+    fn text_node() {
+        let mut reader = Reader::from_str("<enabled> yes </enabled>");
+        reader.read_event().unwrap();
+        let Event::Text(text) = reader.read_event().unwrap() else {
+            panic!("expected text");
+        };
+        assert_eq!(parse_text(&text, Profile::Lenient), Ok(true));
+    }
+}
@@ -0,0 +1,76 @@
+//! Integration with `quickcheck`, for property tests of code that parses lenient booleans.
+//!
+//! `LenientBool` implements `quickcheck::Arbitrary`, and `ValidToken` generates every
+//! accepted spelling, in random case, along with the value it parses to.
+//!
+//! ```
+//! use lenient_bool::quickcheck::ValidToken;
+//! use lenient_bool::LenientBool;
+//! use quickcheck::{Arbitrary, Gen};
+//!
+//! let token = ValidToken::arbitrary(&mut Gen::new(10));
+//! assert_eq!(token.text.parse(), Ok(LenientBool(token.value)));
+//! ```
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+impl Arbitrary for LenientBool {
+    fn arbitrary(g: &mut Gen) -> LenientBool {
+        LenientBool(bool::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = LenientBool>> {
+        Box::new(self.0.shrink().map(LenientBool))
+    }
+}
+
+/// One of the default tokens, with the case of each character chosen at random.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidToken {
+    pub text: String,
+    /// The value that `text` parses to.
+    pub value: bool,
+}
+
+impl Arbitrary for ValidToken {
+    fn arbitrary(g: &mut Gen) -> ValidToken {
+        let value = bool::arbitrary(g);
+        let token = g
+            .choose(if value { TRUE_TOKENS } else { FALSE_TOKENS })
+            .expect("the token lists are never empty");
+        let mut text = String::with_capacity(token.len());
+        for c in token.chars() {
+            if bool::arbitrary(g) {
+                text.extend(c.to_uppercase());
+            } else {
+                text.push(c);
+            }
+        }
+        ValidToken { text, value }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::quickcheck::quickcheck;
+
+    use super::*;
+    use crate::invariants;
+
+    quickcheck! {
+        fn valid_tokens_parse(token: ValidToken) -> bool {
+            token.text.parse() == Ok(LenientBool(token.value))
+        }
+
+        fn arbitrary_round_trips(b: LenientBool) -> bool {
+            b.as_str().parse() == Ok(b)
+        }
+
+        fn invariants_hold(s: String) -> bool {
+            invariants::check_str(&s);
+            true
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Parallel batch parsing with `rayon`.
+
+use ::rayon::prelude::*;
+
+use super::{BatchError, BatchItemError, LenientBool};
+
+/// Parses every value in `values` in parallel on the `rayon` thread pool, like `parse_all`.
+///
+/// The result is the same as `parse_all`'s, including the errors, which are listed in order
+/// of index. This pays off for slices with millions of values, such as a column being
+/// backfilled.
+///
+/// ```
+/// let values: Vec<String> = (0..10_000).map(|i| (i % 2).to_string()).collect();
+/// let parsed = lenient_bool::parse_all_par(&values).unwrap();
+/// assert_eq!(parsed.iter().filter(|b| **b).count(), 5_000);
+///
+/// let err = lenient_bool::parse_all_par(&["yes", "maybe", "0", "?"]).unwrap_err();
+/// assert_eq!(err.items[0].index, 1);
+/// assert_eq!(err.items[1].value, "?");
+/// ```
+pub fn parse_all_par<S: AsRef<str> + Sync>(values: &[S]) -> Result<Vec<bool>, BatchError> {
+    let parsed: Vec<Option<bool>> = values
+        .par_iter()
+        .map(|value| value.as_ref().parse::<LenientBool>().ok().map(bool::from))
+        .collect();
+
+    if parsed.iter().all(Option::is_some) {
+        return Ok(parsed.into_iter().flatten().collect());
+    }
+    let items = parsed
+        .iter()
+        .zip(values)
+        .enumerate()
+        .filter(|(_, (parsed, _))| parsed.is_none())
+        .map(|(index, (_, value))| BatchItemError {
+            index,
+            value: value.as_ref().to_owned(),
+        })
+        .collect();
+    Err(BatchError { items })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_parse_all() {
+        let values: Vec<String> = (0..5000)
+            .map(|i| match i % 7 {
+                0 => "maybe".to_owned(),
+                1 => "Y".to_owned(),
+                _ => (i % 2).to_string(),
+            })
+            .collect();
+        assert_eq!(parse_all_par(&values), crate::parse_all(&values));
+
+        let valid: Vec<&str> = values
+            .iter()
+            .map(|s| s.as_str())
+            .filter(|s| *s != "maybe")
+            .collect();
+        assert_eq!(parse_all_par(&valid), crate::parse_all(&valid));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parse_all_par::<&str>(&[]), Ok(vec![]));
+    }
+}
@@ -0,0 +1,114 @@
+//! Integration with `redis`.
+
+use ::redis::{ErrorKind, FromRedisValue, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use super::LenientBool;
+
+fn parse_reply(s: &str) -> Option<LenientBool> {
+    if s.eq_ignore_ascii_case("ok") {
+        Some(LenientBool(true))
+    } else {
+        s.parse().ok()
+    }
+}
+
+/// Reads a `LenientBool` from a reply.
+///
+/// Depending on the command, Redis reports flags as integers, as an `OK` status, or as
+/// strings. Integers are `false` when zero and `true` otherwise, `OK` is `true`, and strings
+/// are parsed with the `LenientBool` rules. A nil reply is an error; use
+/// `Option<LenientBool>` where the key may not exist.
+impl FromRedisValue for LenientBool {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let parsed = match v {
+            Value::Int(i) => Some(LenientBool(*i != 0)),
+            Value::Boolean(b) => Some(LenientBool(*b)),
+            Value::Okay => Some(LenientBool(true)),
+            Value::SimpleString(s) => parse_reply(s),
+            Value::BulkString(bytes) => std::str::from_utf8(bytes).ok().and_then(parse_reply),
+            _ => None,
+        };
+
+        parsed.ok_or_else(|| {
+            (
+                ErrorKind::TypeError,
+                "Response was of incompatible type",
+                format!("response was not a boolean: {:?}", v),
+            )
+                .into()
+        })
+    }
+}
+
+/// Writes a `LenientBool` as `1` or `0`, the same as `bool`.
+impl ToRedisArgs for LenientBool {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.0.write_redis_args(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn from_value(v: Value) -> Option<LenientBool> {
+        LenientBool::from_redis_value(&v).ok()
+    }
+
+    #[test]
+    fn int() {
+        assert_eq!(from_value(Value::Int(1)), Some(LenientBool(true)));
+        assert_eq!(from_value(Value::Int(0)), Some(LenientBool(false)));
+    }
+
+    #[test]
+    fn okay() {
+        assert_eq!(from_value(Value::Okay), Some(LenientBool(true)));
+    }
+
+    #[test]
+    fn simple_string_ok() {
+        assert_eq!(
+            from_value(Value::SimpleString("OK".to_owned())),
+            Some(LenientBool(true))
+        );
+    }
+
+    #[test]
+    fn bulk_string() {
+        assert_eq!(
+            from_value(Value::BulkString(b"true".to_vec())),
+            Some(LenientBool(true))
+        );
+        assert_eq!(
+            from_value(Value::BulkString(b"0".to_vec())),
+            Some(LenientBool(false))
+        );
+    }
+
+    #[test]
+    fn bulk_string_bad_input_err() {
+        assert_eq!(from_value(Value::BulkString(b"abc".to_vec())), None);
+    }
+
+    #[test]
+    fn nil_err() {
+        assert_eq!(from_value(Value::Nil), None);
+    }
+
+    #[test]
+    fn nil_option() {
+        assert_eq!(
+            Option::<LenientBool>::from_redis_value(&Value::Nil).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn to_args() {
+        assert_eq!(LenientBool(true).to_redis_args(), vec![b"1".to_vec()]);
+    }
+}
@@ -0,0 +1,86 @@
+//! Rhai scripting integration.
+//!
+//! `register` adds the following to an `Engine`, so scripts that evaluate user configuration
+//! can coerce values with the same rules as the Rust code around them:
+//!
+//! * `to_bool(s)` parses a string, raising an error if it isn't recognized. Booleans are
+//!   passed through unchanged.
+//! * `is_bool(s)` checks whether a string would be accepted by `to_bool`.
+//! * The `LenientBool` type, created with `lenient_bool(s)`, with a `value` property.
+//!
+//! ```
+//! let mut engine = rhai::Engine::new();
+//! lenient_bool::rhai::register(&mut engine);
+//!
+//! let result = engine.eval::<bool>(r#"to_bool("Yes") && !to_bool("0")"#).unwrap();
+//! assert!(result);
+//!
+//! assert!(engine.eval::<bool>(r#"to_bool("maybe")"#).is_err());
+//! ```
+
+use ::rhai::{Engine, EvalAltResult, Position};
+
+use super::LenientBool;
+
+fn parse(s: &str) -> Result<LenientBool, Box<EvalAltResult>> {
+    s.parse::<LenientBool>().map_err(|_| {
+        EvalAltResult::ErrorRuntime(
+            format!("invalid boolean value {:?}", s).into(),
+            Position::NONE,
+        )
+        .into()
+    })
+}
+
+/// Registers the `LenientBool` type and the `to_bool`, `is_bool`, and `lenient_bool`
+/// functions with `engine`.
+pub fn register(engine: &mut Engine) -> &mut Engine {
+    engine
+        .register_type_with_name::<LenientBool>("LenientBool")
+        .register_fn("lenient_bool", |s: &str| parse(s))
+        .register_get("value", |b: &mut LenientBool| b.0)
+        .register_fn("to_string", |b: &mut LenientBool| b.0.to_string())
+        .register_fn("to_bool", |s: &str| parse(s).map(bool::from))
+        .register_fn("to_bool", |b: bool| b)
+        .register_fn("is_bool", |s: &str| s.parse::<LenientBool>().is_ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn engine() -> Engine {
+        let mut engine = Engine::new();
+        register(&mut engine);
+        engine
+    }
+
+    #[test]
+    fn to_bool_yes() {
+        assert!(engine().eval::<bool>(r#"to_bool("yes")"#).unwrap());
+    }
+
+    #[test]
+    fn to_bool_passes_bools() {
+        assert!(!engine().eval::<bool>("to_bool(false)").unwrap());
+    }
+
+    #[test]
+    fn to_bool_bad_input_err() {
+        assert!(engine().eval::<bool>(r#"to_bool("abc")"#).is_err());
+    }
+
+    #[test]
+    fn is_bool() {
+        assert!(engine().eval::<bool>(r#"is_bool("N")"#).unwrap());
+        assert!(!engine().eval::<bool>(r#"is_bool("abc")"#).unwrap());
+    }
+
+    #[test]
+    fn lenient_bool_type() {
+        let engine = engine();
+        let b = engine.eval::<LenientBool>(r#"lenient_bool("T")"#).unwrap();
+        assert_eq!(b, LenientBool(true));
+        assert!(engine.eval::<bool>(r#"lenient_bool("1").value"#).unwrap());
+    }
+}
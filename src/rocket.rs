@@ -0,0 +1,101 @@
+//! Integration with the Rocket web framework.
+
+use ::rocket::form::{self, FromFormField, ValueField};
+use ::rocket::request::FromParam;
+
+use super::{LenientBool, LenientBoolError};
+
+/// Allows `LenientBool` to be taken from form bodies and query strings.
+///
+/// As with Rocket's own `bool`, a missing field defaults to `false` and a field that is present
+/// without a value (`?flag` or `flag=`) is `true`. Any other value is parsed with the
+/// `LenientBool` rules, and values that fail to parse produce a validation error, which Rocket
+/// reports as `422 Unprocessable Entity`. The error message names the rejected value, such as
+/// `invalid boolean value "maybe"`, so it can be shown to the user as is.
+impl<'v> FromFormField<'v> for LenientBool {
+    fn default() -> Option<Self> {
+        Some(LenientBool(false))
+    }
+
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        if field.value.is_empty() {
+            return Ok(LenientBool(true));
+        }
+
+        field
+            .value
+            .parse::<LenientBool>()
+            .map_err(|err| form::Error::validation(err.to_string()).into())
+    }
+}
+
+/// Allows `LenientBool` to be taken from a path segment.
+impl<'a> FromParam<'a> for LenientBool {
+    type Error = LenientBoolError;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        param.parse()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::rocket::form::{Form, FromForm};
+
+    use super::*;
+
+    #[derive(Debug, FromForm)]
+    struct Query {
+        flag: LenientBool,
+    }
+
+    fn parse_form(s: &str) -> Result<bool, ()> {
+        Form::<Query>::parse(s).map(|q| *q.flag).map_err(|_| ())
+    }
+
+    #[test]
+    fn form_yes() {
+        assert_eq!(parse_form("flag=yes"), Ok(true));
+    }
+
+    #[test]
+    fn form_0() {
+        assert_eq!(parse_form("flag=0"), Ok(false));
+    }
+
+    #[test]
+    fn form_empty() {
+        assert_eq!(parse_form("flag"), Ok(true));
+        assert_eq!(parse_form("flag="), Ok(true));
+    }
+
+    #[test]
+    fn form_missing() {
+        assert_eq!(parse_form(""), Ok(false));
+    }
+
+    #[test]
+    fn form_bad_input_err() {
+        assert_eq!(parse_form("flag=abc"), Err(()));
+    }
+
+    #[test]
+    fn form_error_names_value() {
+        let errors = Form::<Query>::parse("flag=maybe").unwrap_err();
+        let messages: Vec<String> = errors.iter().map(|e| e.kind.to_string()).collect();
+        assert_eq!(messages, [r#"invalid boolean value "maybe""#]);
+    }
+
+    #[test]
+    fn param_t() {
+        assert_eq!(LenientBool::from_param("T"), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn param_bad_input_err() {
+        assert_eq!(
+            LenientBool::from_param("abc"),
+            Err(LenientBoolError::new("abc"))
+        );
+    }
+}
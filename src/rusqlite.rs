@@ -0,0 +1,146 @@
+//! Integration with `rusqlite`.
+//!
+//! `LenientBool` reads from INTEGER and TEXT columns and writes as an integer. To write text
+//! in the form a schema already uses instead, bind a `Styled` value.
+
+use ::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use super::{BoolStyle, LenientBool};
+
+/// Reads a `LenientBool` from an INTEGER or TEXT column.
+///
+/// SQLite has no boolean type, so schemas variously store flags as integers or as strings.
+/// Integers are `false` when zero and `true` otherwise, matching SQLite's own truthiness.
+/// Text is parsed with the `LenientBool` rules, and text that doesn't parse is an `Other`
+/// error holding the `LenientBoolError`. Any other value, including NULL, is an
+/// `InvalidType` error; use `Option<LenientBool>` for nullable columns.
+impl FromSql for LenientBool {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(i) => Ok(LenientBool(i != 0)),
+            ValueRef::Text(t) => {
+                LenientBool::from_bytes(t).map_err(|err| FromSqlError::Other(Box::new(err)))
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// Writes a `LenientBool` as the integer `0` or `1`, the same as `bool`.
+impl ToSql for LenientBool {
+    fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
+
+/// A `LenientBool` written as TEXT in the given `BoolStyle`.
+///
+/// ```
+/// use lenient_bool::rusqlite::Styled;
+/// use lenient_bool::{BoolStyle, LenientBool};
+/// use rusqlite::Connection;
+///
+/// let conn = Connection::open_in_memory().unwrap();
+/// let value: String = conn
+///     .query_row("SELECT ?1", [Styled(LenientBool(true), BoolStyle::YN)], |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(value, "y");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Styled(pub LenientBool, pub BoolStyle);
+
+impl ToSql for Styled {
+    fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+        let text = self.0.format_as(self.1);
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(text.as_bytes())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::rusqlite::Connection;
+
+    use super::*;
+
+    fn select(value: &str) -> ::rusqlite::Result<LenientBool> {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.query_row(&format!("SELECT {}", value), [], |row| row.get(0))
+    }
+
+    #[test]
+    fn integer_1() {
+        assert_eq!(select("1"), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn integer_0() {
+        assert_eq!(select("0"), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn integer_nonzero() {
+        assert_eq!(select("2"), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn text_yes() {
+        assert_eq!(select("'yes'"), Ok(LenientBool(true)));
+    }
+
+    #[test]
+    fn text_f_upper() {
+        assert_eq!(select("'F'"), Ok(LenientBool(false)));
+    }
+
+    #[test]
+    fn text_bad_input_err() {
+        let err = select("'abc'").unwrap_err();
+        assert!(err.to_string().contains(r#""abc""#), "{}", err);
+    }
+
+    #[test]
+    fn null_err() {
+        assert!(select("NULL").is_err());
+    }
+
+    #[test]
+    fn null_option() {
+        let conn = Connection::open_in_memory().unwrap();
+        let value: Option<LenientBool> =
+            conn.query_row("SELECT NULL", [], |row| row.get(0)).unwrap();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        let value: i64 = conn
+            .query_row("SELECT ?1", [LenientBool(true)], |row| row.get(0))
+            .unwrap();
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    fn styled_round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        for style in [
+            BoolStyle::TrueFalse,
+            BoolStyle::YesNo,
+            BoolStyle::Numeric,
+            BoolStyle::YN,
+        ] {
+            let written: String = conn
+                .query_row("SELECT ?1", [Styled(LenientBool(false), style)], |row| {
+                    row.get(0)
+                })
+                .unwrap();
+            assert_eq!(written, LenientBool(false).format_as(style));
+            let read: LenientBool = conn
+                .query_row("SELECT ?1", [Styled(LenientBool(false), style)], |row| {
+                    row.get(0)
+                })
+                .unwrap();
+            assert_eq!(read, LenientBool(false));
+        }
+    }
+}
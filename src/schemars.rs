@@ -0,0 +1,91 @@
+//! Integration with `schemars`, for JSON Schema and OpenAPI documents.
+
+use std::borrow::Cow;
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+/// A regular expression that matches `token` in any case, such as `[yY][eE][sS]` for `yes`.
+///
+/// JSON Schema patterns have no case-insensitive flag, so each letter gets a character class.
+fn case_insensitive_pattern(token: &str) -> String {
+    let mut pattern = String::new();
+    for c in token.chars() {
+        let upper: String = c.to_uppercase().collect();
+        if upper.chars().eq([c]) {
+            pattern.push(c);
+        } else {
+            pattern.push('[');
+            pattern.push(c);
+            pattern.push_str(&upper);
+            pattern.push(']');
+        }
+    }
+    pattern
+}
+
+/// Describes what `LenientBool` deserializes from: a native boolean, the integers 0 and 1, or
+/// one of the accepted strings in any case.
+impl JsonSchema for LenientBool {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("LenientBool")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let tokens: Vec<&str> = TRUE_TOKENS.iter().chain(FALSE_TOKENS).copied().collect();
+        let alternatives: Vec<String> =
+            tokens.iter().map(|t| case_insensitive_pattern(t)).collect();
+        let description = format!(
+            "A boolean, 0 or 1, or one of these strings in any case: {}",
+            tokens.join(", ")
+        );
+        json_schema!({
+            "description": description,
+            "anyOf": [
+                { "type": "boolean" },
+                { "type": "integer", "enum": [0, 1] },
+                { "type": "string", "pattern": format!("^({})$", alternatives.join("|")) },
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::schemars::schema_for;
+
+    use super::*;
+
+    #[test]
+    fn pattern() {
+        assert_eq!(case_insensitive_pattern("yes"), "[yY][eE][sS]");
+        assert_eq!(case_insensitive_pattern("1"), "1");
+    }
+
+    #[test]
+    fn schema() {
+        let schema = schema_for!(LenientBool);
+        let any_of = schema.get("anyOf").unwrap();
+        assert_eq!(any_of[0], serde_json::json!({ "type": "boolean" }));
+        assert_eq!(any_of[1]["enum"], serde_json::json!([0, 1]));
+        let pattern = any_of[2]["pattern"].as_str().unwrap();
+        assert!(pattern.starts_with("^([tT][rR][uU][eE]|"), "{}", pattern);
+        assert!(pattern.contains("|[nN][oO]|"), "{}", pattern);
+    }
+
+    #[test]
+    fn schema_in_struct() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Config {
+            verbose: LenientBool,
+        }
+
+        let schema = serde_json::to_value(schema_for!(Config)).unwrap();
+        assert_eq!(
+            schema["properties"]["verbose"]["$ref"],
+            "#/$defs/LenientBool"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Integration with SeaORM.
+//!
+//! A `LenientBool` column can be backed by boolean, integer, or text data. Integers are
+//! `false` when zero and `true` otherwise, and text is parsed with the `LenientBool` rules.
+//! Values are always written back as native booleans.
+
+use ::sea_orm::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use ::sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+
+use super::LenientBool;
+
+impl From<LenientBool> for Value {
+    fn from(b: LenientBool) -> Value {
+        Value::Bool(Some(b.0))
+    }
+}
+
+impl Nullable for LenientBool {
+    fn null() -> Value {
+        Value::Bool(None)
+    }
+}
+
+impl ValueType for LenientBool {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Bool(Some(b)) => Ok(LenientBool(b)),
+            Value::TinyInt(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::SmallInt(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::Int(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::BigInt(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::TinyUnsigned(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::SmallUnsigned(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::Unsigned(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::BigUnsigned(Some(i)) => Ok(LenientBool(i != 0)),
+            Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "LenientBool".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bool
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Boolean
+    }
+}
+
+impl TryGetable for LenientBool {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        // Not every backend distinguishes a mismatched type from NULL, so try each
+        // representation in turn and let the last one report the error.
+        if let Ok(b) = bool::try_get_by(res, index) {
+            return Ok(LenientBool(b));
+        }
+        if let Ok(i) = i64::try_get_by(res, index) {
+            return Ok(LenientBool(i != 0));
+        }
+
+        let s = String::try_get_by(res, index)?;
+        s.parse()
+            .map_err(|_| TryGetError::DbErr(DbErr::Type(format!("invalid boolean value {:?}", s))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use ::sea_orm::{ConnectionTrait, DbBackend, MockDatabase, Statement};
+
+    use super::*;
+
+    fn query(value: Value) -> Result<Option<LenientBool>, DbErr> {
+        let row = BTreeMap::from([("flag", value)]);
+        let conn = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([vec![row]])
+            .into_connection();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let res = conn
+                .query_one(Statement::from_string(DbBackend::Sqlite, "SELECT flag"))
+                .await?
+                .unwrap();
+            res.try_get::<Option<LenientBool>>("", "flag")
+        })
+    }
+
+    #[test]
+    fn get_bool() {
+        assert_eq!(query(Value::Bool(Some(true))), Ok(Some(LenientBool(true))));
+    }
+
+    #[test]
+    fn get_integer() {
+        assert_eq!(query(Value::BigInt(Some(0))), Ok(Some(LenientBool(false))));
+    }
+
+    #[test]
+    fn get_text() {
+        assert_eq!(query(Value::from("yes")), Ok(Some(LenientBool(true))));
+    }
+
+    #[test]
+    fn get_null() {
+        assert_eq!(query(Value::Bool(None)), Ok(None));
+    }
+
+    #[test]
+    fn get_bad_input_err() {
+        assert!(query(Value::from("abc")).is_err());
+    }
+
+    #[test]
+    fn value_type() {
+        assert_eq!(
+            <LenientBool as ValueType>::try_from(Value::from("N")).ok(),
+            Some(LenientBool(false))
+        );
+        assert_eq!(
+            <LenientBool as ValueType>::try_from(Value::Int(Some(1))).ok(),
+            Some(LenientBool(true))
+        );
+        assert!(<LenientBool as ValueType>::try_from(Value::Double(Some(1.0))).is_err());
+    }
+
+    #[test]
+    fn into_value() {
+        assert_eq!(Value::from(LenientBool(true)), Value::Bool(Some(true)));
+    }
+}
@@ -0,0 +1,874 @@
+//! Serde support for `LenientBool`.
+
+use std::cell::Cell;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use ::serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use ::serde::{Serialize, Serializer};
+
+use super::{LenientBool, LenientBoolError};
+
+struct LenientBoolVisitor;
+
+impl<'de> Visitor<'de> for LenientBoolVisitor {
+    type Value = LenientBool;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a boolean string such as true, false, yes, no, 1, or 0")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<LenientBool, E>
+    where
+        E: de::Error,
+    {
+        v.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<LenientBool, E> {
+        Ok(LenientBool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<LenientBool, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            0 => Ok(LenientBool(false)),
+            1 => Ok(LenientBool(true)),
+            _ => Err(E::invalid_value(de::Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<LenientBool, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            0 => Ok(LenientBool(false)),
+            1 => Ok(LenientBool(true)),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+/// Deserializes a `LenientBool` from a string, using the same rules as `FromStr`, or from a
+/// native boolean or the integers 0 and 1.
+///
+/// This lets the same field accept `true`, `1`, and `"yes"` from JSON APIs that aren't
+/// consistent about their types:
+///
+/// ```
+/// use lenient_bool::LenientBool;
+///
+/// let values: Vec<LenientBool> = serde_json::from_str(r#"[true, 1, "yes", 0]"#).unwrap();
+/// assert_eq!(values, [LenientBool(true), LenientBool(true), LenientBool(true), LenientBool(false)]);
+/// ```
+///
+/// Since the type comes from the input, this needs a self-describing format. It works with
+/// any format that supplies strings, including environment variables read with `envy`:
+///
+/// ```
+/// use lenient_bool::LenientBool;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct EnvFlags {
+///     verbose: LenientBool,
+///     #[serde(default)]
+///     dry_run: LenientBool,
+///     color: Option<LenientBool>,
+/// }
+///
+/// let vars = [("VERBOSE".to_owned(), "Yes".to_owned())];
+/// let flags: EnvFlags = envy::from_iter(vars).unwrap();
+/// assert!(*flags.verbose);
+/// assert!(!*flags.dry_run);
+/// assert_eq!(flags.color, None);
+/// ```
+///
+/// `LenientBool` can also be used as a map key, since formats such as JSON store keys as
+/// strings:
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use lenient_bool::LenientBool;
+///
+/// let json = r#"{ "yes": ["alice"], "0": ["bob", "carol"] }"#;
+/// let groups: BTreeMap<LenientBool, Vec<String>> = serde_json::from_str(json).unwrap();
+/// assert_eq!(groups[&LenientBool(true)], ["alice"]);
+/// assert_eq!(groups[&LenientBool(false)].len(), 2);
+/// ```
+///
+/// Formats that aren't human-readable, such as bincode, store a native boolean, which is
+/// what `Serialize` writes for them.
+impl<'de> Deserialize<'de> for LenientBool {
+    fn deserialize<D>(deserializer: D) -> Result<LenientBool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(LenientBoolVisitor)
+        } else {
+            deserializer.deserialize_bool(LenientBoolVisitor)
+        }
+    }
+}
+
+/// A boolean that deserializes leniently, falling back to `D` when the value is missing or
+/// can't be understood.
+///
+/// Strings are parsed with the `LenientBool` rules, booleans are used as they are, and the
+/// integers 0 and 1 are accepted. Missing fields, nulls, and anything else produce `D`
+/// instead of an error, so each field of a config struct can have its own fallback.
+///
+/// `DefaultTrue` and `DefaultFalse` are shorthands for the two variants, also available as
+/// `LenientBoolDefaultTrue` and `LenientBoolDefaultFalse`.
+///
+/// ```
+/// use lenient_bool::{DefaultFalse, DefaultTrue};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     cache: DefaultTrue,
+///     tracing: DefaultFalse,
+///     metrics: DefaultTrue,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{ "tracing": "yes", "metrics": "sometimes" }"#).unwrap();
+/// assert!(*config.cache);
+/// assert!(*config.tracing);
+/// assert!(*config.metrics);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct LenientBoolOr<const D: bool>(pub bool);
+
+/// A lenient boolean that is `true` when missing or invalid.
+pub type DefaultTrue = LenientBoolOr<true>;
+
+/// A lenient boolean that is `false` when missing or invalid.
+pub type DefaultFalse = LenientBoolOr<false>;
+
+/// Another name for `DefaultTrue`.
+pub type LenientBoolDefaultTrue = DefaultTrue;
+
+/// Another name for `DefaultFalse`.
+pub type LenientBoolDefaultFalse = DefaultFalse;
+
+impl<const D: bool> Default for LenientBoolOr<D> {
+    fn default() -> LenientBoolOr<D> {
+        LenientBoolOr(D)
+    }
+}
+
+impl<const D: bool> From<LenientBoolOr<D>> for bool {
+    fn from(b: LenientBoolOr<D>) -> bool {
+        b.0
+    }
+}
+
+impl<const D: bool> From<bool> for LenientBoolOr<D> {
+    fn from(b: bool) -> LenientBoolOr<D> {
+        LenientBoolOr(b)
+    }
+}
+
+/// Parses a string with the `LenientBool` rules, giving `D` for an empty or all-whitespace
+/// string. Unlike deserializing, other invalid values are an error.
+///
+/// ```
+/// use lenient_bool::{DefaultFalse, DefaultTrue};
+///
+/// assert_eq!("".parse(), Ok(DefaultTrue::default()));
+/// assert_eq!("yes".parse(), Ok(DefaultFalse::from(true)));
+/// assert!("maybe".parse::<DefaultTrue>().is_err());
+/// ```
+impl<const D: bool> FromStr for LenientBoolOr<D> {
+    type Err = LenientBoolError;
+
+    fn from_str(s: &str) -> Result<LenientBoolOr<D>, LenientBoolError> {
+        if s.trim().is_empty() {
+            return Ok(LenientBoolOr(D));
+        }
+        s.parse::<LenientBool>().map(|b| LenientBoolOr(b.0))
+    }
+}
+
+impl<const D: bool> Deref for LenientBoolOr<D> {
+    type Target = bool;
+    fn deref(&self) -> &bool {
+        &self.0
+    }
+}
+
+struct LenientBoolOrVisitor<const D: bool>;
+
+impl<'de, const D: bool> Visitor<'de> for LenientBoolOrVisitor<D> {
+    type Value = LenientBoolOr<D>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(v.parse::<LenientBool>().map_or(D, |b| b.0)))
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(match v {
+            0 => false,
+            1 => true,
+            _ => D,
+        }))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(match v {
+            0 => false,
+            1 => true,
+            _ => D,
+        }))
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(D))
+    }
+
+    fn visit_bytes<E>(self, _: &[u8]) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(D))
+    }
+
+    fn visit_none<E>(self) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(D))
+    }
+
+    fn visit_unit<E>(self) -> Result<LenientBoolOr<D>, E> {
+        Ok(LenientBoolOr(D))
+    }
+
+    fn visit_some<T>(self, deserializer: T) -> Result<LenientBoolOr<D>, T::Error>
+    where
+        T: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<LenientBoolOr<D>, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(LenientBoolOr(D))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<LenientBoolOr<D>, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+        Ok(LenientBoolOr(D))
+    }
+}
+
+/// Deserializes a `LenientBoolOr`, using `D` for missing and invalid values.
+///
+/// This needs a self-describing format, such as JSON, TOML, or environment variables read
+/// with `envy`.
+impl<'de, const D: bool> Deserialize<'de> for LenientBoolOr<D> {
+    fn deserialize<T>(deserializer: T) -> Result<LenientBoolOr<D>, T::Error>
+    where
+        T: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_bool(LenientBoolOrVisitor::<D>);
+        }
+        // Going through `deserialize_option` lets serde's derive treat a missing field as
+        // `None`, without needing `#[serde(default)]`.
+        deserializer.deserialize_option(LenientBoolOrVisitor::<D>)
+    }
+}
+
+thread_local! {
+    static STYLE: Cell<SerializeStyle> = const { Cell::new(SerializeStyle::Bool) };
+}
+
+/// The form that `LenientBool` and `LenientBoolOr` are written in when serialized.
+///
+/// The style is native booleans unless changed for the current thread with
+/// `LenientBool::with_serialize_style`. Formats that aren't human-readable, such as bincode,
+/// always get a native boolean, since a string would only make them bigger and slower.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SerializeStyle {
+    /// A native boolean.
+    #[default]
+    Bool,
+    /// The strings `true` and `false`.
+    TrueFalse,
+    /// The strings `yes` and `no`.
+    YesNo,
+    /// The strings `1` and `0`.
+    OneZero,
+}
+
+impl SerializeStyle {
+    fn serialize<S>(self, value: bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bool(value);
+        }
+        let (true_text, false_text) = match self {
+            SerializeStyle::Bool => return serializer.serialize_bool(value),
+            SerializeStyle::TrueFalse => ("true", "false"),
+            SerializeStyle::YesNo => ("yes", "no"),
+            SerializeStyle::OneZero => ("1", "0"),
+        };
+        serializer.serialize_str(if value { true_text } else { false_text })
+    }
+}
+
+struct RestoreStyle(SerializeStyle);
+
+impl Drop for RestoreStyle {
+    fn drop(&mut self) {
+        STYLE.with(|style| style.set(self.0));
+    }
+}
+
+impl LenientBool {
+    /// Runs `f` with `style` used for serializing `LenientBool` and `LenientBoolOr` values on
+    /// the current thread.
+    ///
+    /// This is useful for reading values leniently but writing them back in the fixed form
+    /// another system expects. The previous style is restored when `f` returns or panics.
+    ///
+    /// ```
+    /// use lenient_bool::{LenientBool, SerializeStyle};
+    ///
+    /// let value = LenientBool(true);
+    /// assert_eq!(serde_json::to_string(&value).unwrap(), "true");
+    ///
+    /// let legacy = LenientBool::with_serialize_style(SerializeStyle::YesNo, || {
+    ///     serde_json::to_string(&value).unwrap()
+    /// });
+    /// assert_eq!(legacy, r#""yes""#);
+    /// ```
+    pub fn with_serialize_style<R>(style: SerializeStyle, f: impl FnOnce() -> R) -> R {
+        let previous = STYLE.with(|current| current.replace(style));
+        let _restore = RestoreStyle(previous);
+        f()
+    }
+}
+
+/// Serializes a `LenientBool` in the current `SerializeStyle`.
+impl Serialize for LenientBool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        STYLE.with(Cell::get).serialize(self.0, serializer)
+    }
+}
+
+/// Serializes a `LenientBoolOr` in the current `SerializeStyle`.
+impl<const D: bool> Serialize for LenientBoolOr<D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        STYLE.with(Cell::get).serialize(self.0, serializer)
+    }
+}
+
+/// Lenient parsing for plain `bool` fields, with `#[serde(with = "lenient_bool::as_bool")]`.
+///
+/// Values are read with the same rules as `LenientBool` and written as native booleans.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Settings {
+///     #[serde(with = "lenient_bool::as_bool")]
+///     notify: bool,
+/// }
+///
+/// let settings: Settings = serde_json::from_str(r#"{ "notify": "Y" }"#).unwrap();
+/// assert!(settings.notify);
+/// assert_eq!(serde_json::to_string(&settings).unwrap(), r#"{"notify":true}"#);
+/// ```
+pub mod as_bool {
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::LenientBool;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        LenientBool::deserialize(deserializer).map(bool::from)
+    }
+
+    pub fn serialize<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(*value)
+    }
+}
+
+/// Like `as_bool`, but written as the strings `true` and `false`, for formats and consumers
+/// that expect strings.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Settings {
+///     #[serde(with = "lenient_bool::as_bool_str")]
+///     notify: bool,
+/// }
+///
+/// let settings: Settings = serde_json::from_str(r#"{ "notify": "no" }"#).unwrap();
+/// assert_eq!(serde_json::to_string(&settings).unwrap(), r#"{"notify":"false"}"#);
+/// ```
+pub mod as_bool_str {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::SerializeStyle;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::as_bool::deserialize(deserializer)
+    }
+
+    pub fn serialize<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializeStyle::TrueFalse.serialize(*value, serializer)
+    }
+}
+
+/// Lenient parsing for `Option<bool>` fields, where null is `None`.
+///
+/// Add `#[serde(default)]` as well to treat missing fields as `None`, since serde requires
+/// fields with a custom deserializer to be present otherwise.
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Settings {
+///     #[serde(default, deserialize_with = "lenient_bool::opt_as_bool::deserialize")]
+///     notify: Option<bool>,
+/// }
+///
+/// let settings: Settings = serde_json::from_str(r#"{ "notify": "yes" }"#).unwrap();
+/// assert_eq!(settings.notify, Some(true));
+/// let settings: Settings = serde_json::from_str(r#"{ "notify": null }"#).unwrap();
+/// assert_eq!(settings.notify, None);
+/// let settings: Settings = serde_json::from_str("{}").unwrap();
+/// assert_eq!(settings.notify, None);
+/// ```
+pub mod opt_as_bool {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::LenientBool;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<LenientBool>::deserialize(deserializer).map(|v| v.map(bool::from))
+    }
+
+    pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+}
+
+/// Lenient parsing for `Vec<bool>` fields, parsing each element with the `LenientBool`
+/// rules.
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Settings {
+///     #[serde(deserialize_with = "lenient_bool::vec_as_bool::deserialize")]
+///     channels: Vec<bool>,
+/// }
+///
+/// let settings: Settings = serde_json::from_str(r#"{ "channels": ["Y", "n", "1"] }"#).unwrap();
+/// assert_eq!(settings.channels, [true, false, true]);
+/// ```
+pub mod vec_as_bool {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::LenientBool;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<LenientBool>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(bool::from).collect())
+    }
+
+    pub fn serialize<S>(value: &[bool], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+}
+
+/// Lenient parsing for `bool` fields where null means `false`, as is common for optional
+/// feature flags.
+///
+/// Add `#[serde(default)]` as well to treat missing fields as `false`.
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Features {
+///     #[serde(default, with = "lenient_bool::null_as_false")]
+///     beta: bool,
+/// }
+///
+/// let features: Features = serde_json::from_str(r#"{ "beta": null }"#).unwrap();
+/// assert!(!features.beta);
+/// let features: Features = serde_json::from_str(r#"{ "beta": "yes" }"#).unwrap();
+/// assert!(features.beta);
+/// ```
+pub mod null_as_false {
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::LenientBool;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<LenientBool>::deserialize(deserializer).map(|v| v.is_some_and(bool::from))
+    }
+
+    pub fn serialize<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(*value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, HashMap};
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct EnvFlags {
+        foo: LenientBool,
+    }
+
+    fn from_env(value: &str) -> Result<bool, envy::Error> {
+        let vars = [("FOO".to_owned(), value.to_owned())];
+        envy::from_iter::<_, EnvFlags>(vars).map(|f| *f.foo)
+    }
+
+    #[test]
+    fn envy_yes_cap() {
+        assert!(from_env("Yes").unwrap());
+    }
+
+    #[test]
+    fn envy_1() {
+        assert!(from_env("1").unwrap());
+    }
+
+    #[test]
+    fn envy_n() {
+        assert!(!from_env("n").unwrap());
+    }
+
+    #[test]
+    fn envy_off_err() {
+        // `off` is not one of the accepted values.
+        assert!(from_env("off").is_err());
+    }
+
+    #[test]
+    fn envy_missing_err() {
+        let vars: [(String, String); 0] = [];
+        assert!(envy::from_iter::<_, EnvFlags>(vars).is_err());
+    }
+
+    #[test]
+    fn json_field() {
+        let flags: EnvFlags = serde_json::from_str(r#"{ "foo": "Y" }"#).unwrap();
+        assert!(*flags.foo);
+    }
+
+    #[test]
+    fn json_field_bad_value_err() {
+        let err = serde_json::from_str::<EnvFlags>(r#"{ "foo": "maybe" }"#).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "invalid value: string \"maybe\", expected a boolean string such as true"
+        ));
+    }
+
+    #[test]
+    fn json_native_values() {
+        let values: Vec<LenientBool> = serde_json::from_str("[true, false, 1, 0]").unwrap();
+        let values: Vec<bool> = values.into_iter().map(bool::from).collect();
+        assert_eq!(values, [true, false, true, false]);
+    }
+
+    #[test]
+    fn json_other_numbers_err() {
+        for json in ["2", "-1", "1.0"] {
+            assert!(
+                serde_json::from_str::<LenientBool>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn json_map_keys() {
+        let json = r#"{ "T": 1, "no": 2 }"#;
+        let map: HashMap<LenientBool, u32> = serde_json::from_str(json).unwrap();
+        assert_eq!(map[&LenientBool(true)], 1);
+        assert_eq!(map[&LenientBool(false)], 2);
+    }
+
+    #[test]
+    fn json_map_keys_round_trip() {
+        let map: BTreeMap<LenientBool, u32> =
+            [(LenientBool(false), 2), (LenientBool(true), 1)].into();
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"false":2,"true":1}"#);
+        assert_eq!(
+            serde_json::from_str::<BTreeMap<LenientBool, u32>>(&json).unwrap(),
+            map
+        );
+
+        let json = LenientBool::with_serialize_style(SerializeStyle::YesNo, || {
+            serde_json::to_string(&map).unwrap()
+        });
+        assert_eq!(
+            serde_json::from_str::<BTreeMap<LenientBool, u32>>(&json).unwrap(),
+            map
+        );
+    }
+
+    #[test]
+    fn json_map_bad_key_err() {
+        let json = r#"{ "maybe": 1 }"#;
+        assert!(serde_json::from_str::<HashMap<LenientBool, u32>>(json).is_err());
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Fallbacks {
+        on: DefaultTrue,
+        off: DefaultFalse,
+    }
+
+    fn fallbacks(json: &str) -> (bool, bool) {
+        let f: Fallbacks = serde_json::from_str(json).unwrap();
+        (*f.on, *f.off)
+    }
+
+    #[test]
+    fn fallback_parses_strings() {
+        assert_eq!(fallbacks(r#"{ "on": "no", "off": "Y" }"#), (false, true));
+    }
+
+    #[test]
+    fn fallback_accepts_bools_and_ints() {
+        assert_eq!(fallbacks(r#"{ "on": false, "off": 1 }"#), (false, true));
+    }
+
+    #[test]
+    fn fallback_missing() {
+        assert_eq!(fallbacks("{}"), (true, false));
+    }
+
+    #[test]
+    fn fallback_invalid() {
+        assert_eq!(
+            fallbacks(r#"{ "on": "abc", "off": [1, 2] }"#),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn fallback_null() {
+        assert_eq!(fallbacks(r#"{ "on": null, "off": 7 }"#), (true, false));
+    }
+
+    #[test]
+    fn fallback_envy() {
+        let vars = [("ON".to_owned(), "maybe".to_owned())];
+        let f: Fallbacks = envy::from_iter(vars).unwrap();
+        assert!(*f.on);
+        assert!(!*f.off);
+    }
+
+    #[test]
+    fn fallback_from_str() {
+        assert_eq!("".parse(), Ok(LenientBoolDefaultTrue::default()));
+        assert_eq!("  ".parse(), Ok(LenientBoolDefaultFalse::default()));
+        assert_eq!("N".parse(), Ok(LenientBoolDefaultTrue::from(false)));
+        assert_eq!(
+            "abc".parse::<LenientBoolDefaultFalse>(),
+            Err(LenientBoolError::new("abc"))
+        );
+    }
+
+    #[test]
+    fn binary_format_uses_bools() {
+        let bytes = LenientBool::with_serialize_style(SerializeStyle::YesNo, || {
+            bincode::serialize(&(LenientBool(true), DefaultFalse::default())).unwrap()
+        });
+        assert_eq!(bytes, [1, 0]);
+        assert_eq!(
+            bincode::deserialize::<(LenientBool, DefaultTrue)>(&bytes).unwrap(),
+            (LenientBool(true), LenientBoolOr(false))
+        );
+        assert!(bincode::deserialize::<LenientBool>(&[2]).is_err());
+    }
+
+    fn to_json<T: Serialize>(style: SerializeStyle, value: T) -> String {
+        LenientBool::with_serialize_style(style, || serde_json::to_string(&value).unwrap())
+    }
+
+    #[test]
+    fn serialize_default_bool() {
+        assert_eq!(serde_json::to_string(&LenientBool(false)).unwrap(), "false");
+    }
+
+    #[test]
+    fn serialize_styles() {
+        assert_eq!(
+            to_json(SerializeStyle::TrueFalse, LenientBool(true)),
+            r#""true""#
+        );
+        assert_eq!(
+            to_json(SerializeStyle::YesNo, LenientBool(false)),
+            r#""no""#
+        );
+        assert_eq!(
+            to_json(SerializeStyle::OneZero, DefaultTrue::default()),
+            r#""1""#
+        );
+    }
+
+    #[test]
+    fn serialize_style_restored() {
+        let nested = LenientBool::with_serialize_style(SerializeStyle::YesNo, || {
+            let inner = to_json(SerializeStyle::OneZero, LenientBool(true));
+            (inner, serde_json::to_string(&LenientBool(true)).unwrap())
+        });
+        assert_eq!(nested, (r#""1""#.to_owned(), r#""yes""#.to_owned()));
+        assert_eq!(serde_json::to_string(&LenientBool(true)).unwrap(), "true");
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Plain {
+        #[serde(with = "as_bool")]
+        a: bool,
+        #[serde(with = "as_bool_str")]
+        b: bool,
+    }
+
+    #[test]
+    fn plain_bool_round_trip() {
+        let plain: Plain = serde_json::from_str(r#"{ "a": "yes", "b": "0" }"#).unwrap();
+        assert!(plain.a);
+        assert!(!plain.b);
+        assert_eq!(
+            serde_json::to_string(&plain).unwrap(),
+            r#"{"a":true,"b":"false"}"#
+        );
+    }
+
+    #[test]
+    fn plain_bool_bad_value_err() {
+        assert!(serde_json::from_str::<Plain>(r#"{ "a": "maybe", "b": "1" }"#).is_err());
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Collections {
+        #[serde(default, with = "opt_as_bool")]
+        maybe: Option<bool>,
+        #[serde(default, with = "vec_as_bool")]
+        all: Vec<bool>,
+    }
+
+    #[test]
+    fn collections() {
+        let c: Collections =
+            serde_json::from_str(r#"{ "maybe": "F", "all": ["yes", "0"] }"#).unwrap();
+        assert_eq!(c.maybe, Some(false));
+        assert_eq!(c.all, [true, false]);
+        assert_eq!(
+            serde_json::to_string(&c).unwrap(),
+            r#"{"maybe":false,"all":[true,false]}"#
+        );
+    }
+
+    #[test]
+    fn collections_missing() {
+        let c: Collections = serde_json::from_str("{}").unwrap();
+        assert_eq!(c.maybe, None);
+        assert!(c.all.is_empty());
+    }
+
+    #[test]
+    fn collections_bad_element_err() {
+        assert!(serde_json::from_str::<Collections>(r#"{ "all": ["yes", "maybe"] }"#).is_err());
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Nullable {
+        #[serde(default, with = "null_as_false")]
+        flag: bool,
+    }
+
+    fn nullable(json: &str) -> bool {
+        serde_json::from_str::<Nullable>(json).unwrap().flag
+    }
+
+    #[test]
+    fn null_as_false() {
+        assert!(!nullable(r#"{ "flag": null }"#));
+        assert!(!nullable("{}"));
+        assert!(nullable(r#"{ "flag": "T" }"#));
+        assert!(nullable(r#"{ "flag": true }"#));
+    }
+
+    #[test]
+    fn null_as_false_bad_value_err() {
+        assert!(serde_json::from_str::<Nullable>(r#"{ "flag": "maybe" }"#).is_err());
+    }
+}
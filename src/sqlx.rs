@@ -0,0 +1,157 @@
+//! Integration with sqlx.
+//!
+//! The implementations are generic over the database, so they work with the Postgres, MySQL,
+//! and SQLite drivers alike. A `LenientBool` can be decoded from native boolean columns,
+//! integer columns (zero is `false`, anything else is `true`), and text columns, which are
+//! parsed with the `LenientBool` rules. It is always encoded as a native boolean.
+//!
+//! A text value that doesn't parse fails with a `ColumnDecode` error whose source is the
+//! `LenientBoolError`.
+
+use ::sqlx::decode::Decode;
+use ::sqlx::encode::{Encode, IsNull};
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Type, ValueRef};
+
+use super::LenientBool;
+
+impl<DB> Type<DB> for LenientBool
+where
+    DB: Database,
+    bool: Type<DB>,
+    i16: Type<DB>,
+    i32: Type<DB>,
+    i64: Type<DB>,
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <bool as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <bool as Type<DB>>::compatible(ty)
+            || <i16 as Type<DB>>::compatible(ty)
+            || <i32 as Type<DB>>::compatible(ty)
+            || <i64 as Type<DB>>::compatible(ty)
+            || <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for LenientBool
+where
+    DB: Database,
+    bool: Decode<'r, DB> + Type<DB>,
+    i16: Decode<'r, DB> + Type<DB>,
+    i32: Decode<'r, DB> + Type<DB>,
+    i64: Decode<'r, DB> + Type<DB>,
+    &'r str: Decode<'r, DB> + Type<DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let ty = value.type_info().into_owned();
+        if <bool as Type<DB>>::compatible(&ty) {
+            <bool as Decode<DB>>::decode(value).map(LenientBool)
+        } else if <i64 as Type<DB>>::compatible(&ty) {
+            <i64 as Decode<DB>>::decode(value).map(|i| LenientBool(i != 0))
+        } else if <i32 as Type<DB>>::compatible(&ty) {
+            <i32 as Decode<DB>>::decode(value).map(|i| LenientBool(i != 0))
+        } else if <i16 as Type<DB>>::compatible(&ty) {
+            <i16 as Decode<DB>>::decode(value).map(|i| LenientBool(i != 0))
+        } else {
+            let s = <&str as Decode<DB>>::decode(value)?;
+            Ok(s.parse::<LenientBool>()?)
+        }
+    }
+}
+
+impl<'q, DB> Encode<'q, DB> for LenientBool
+where
+    DB: Database,
+    bool: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        <bool as Encode<DB>>::encode_by_ref(&self.0, buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::sqlx::{Connection, SqliteConnection};
+
+    use super::*;
+
+    fn select(value: &str) -> Result<LenientBool, ::sqlx::Error> {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+            ::sqlx::query_scalar(&format!("SELECT {}", value))
+                .fetch_one(&mut conn)
+                .await
+        })
+    }
+
+    #[test]
+    fn integer_1() {
+        assert_eq!(select("1").unwrap(), LenientBool(true));
+    }
+
+    #[test]
+    fn integer_0() {
+        assert_eq!(select("0").unwrap(), LenientBool(false));
+    }
+
+    #[test]
+    fn text_yes() {
+        assert_eq!(select("'yes'").unwrap(), LenientBool(true));
+    }
+
+    #[test]
+    fn text_f_upper() {
+        assert_eq!(select("'F'").unwrap(), LenientBool(false));
+    }
+
+    #[test]
+    fn text_bad_input_err() {
+        assert!(select("'abc'").is_err());
+    }
+
+    #[test]
+    fn text_bad_input_source() {
+        match select("'maybe'") {
+            Err(::sqlx::Error::ColumnDecode { source, .. }) => assert_eq!(
+                source
+                    .downcast_ref::<crate::LenientBoolError>()
+                    .map(|e| e.input()),
+                Some("maybe")
+            ),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn real_err() {
+        assert!(select("1.5").is_err());
+    }
+
+    #[test]
+    fn bind() {
+        assert_eq!(select_bound(LenientBool(true)).unwrap(), 1);
+    }
+
+    fn select_bound(value: LenientBool) -> Result<i64, ::sqlx::Error> {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+            ::sqlx::query_scalar("SELECT ?")
+                .bind(value)
+                .fetch_one(&mut conn)
+                .await
+        })
+    }
+}
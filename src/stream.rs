@@ -0,0 +1,198 @@
+//! Parsing a stream of values, one per line, such as a single-column flag file.
+//!
+//! ```
+//! use lenient_bool::stream::parse_lines;
+//!
+//! let input = "yes\nN\n  true \r\nmaybe\n";
+//! let values: Vec<_> = parse_lines(input.as_bytes()).collect();
+//! assert_eq!(values[..3], [Ok(true), Ok(false), Ok(true)]);
+//! assert_eq!(values[3].as_ref().unwrap_err().to_string(), r#"line 4: invalid boolean value "maybe""#);
+//! ```
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+use super::LenientBool;
+
+/// Returns an iterator that parses each line of `reader` with the `LenientBool` rules.
+///
+/// Lines are split on `\n`, and whitespace around each value, including a `\r` from Windows
+/// line endings, is ignored. Lines don't have to be UTF-8. Blank lines are errors, like any
+/// other unrecognized value. After an I/O error, the iterator ends.
+pub fn parse_lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines {
+        reader,
+        line: 0,
+        buf: Vec::new(),
+        done: false,
+    }
+}
+
+/// The iterator returned by `parse_lines`.
+#[derive(Debug)]
+pub struct Lines<R> {
+    reader: R,
+    line: usize,
+    buf: Vec<u8>,
+    done: bool,
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<bool, LineError>;
+
+    fn next(&mut self) -> Option<Result<bool, LineError>> {
+        if self.done {
+            return None;
+        }
+        self.buf.clear();
+        self.line += 1;
+        match self.reader.read_until(b'\n', &mut self.buf) {
+            Ok(0) => {
+                self.done = true;
+                None
+            }
+            Ok(_) => {
+                let value = self.buf.trim_ascii();
+                Some(LenientBool::from_bytes(value).map(bool::from).map_err(|_| {
+                    LineError::Invalid {
+                        line: self.line,
+                        content: String::from_utf8_lossy(value).into_owned(),
+                    }
+                }))
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(LineError::Io {
+                    line: self.line,
+                    error,
+                }))
+            }
+        }
+    }
+}
+
+/// An error from `parse_lines`.
+#[derive(Debug)]
+pub enum LineError {
+    /// The line isn't a recognized boolean value.
+    Invalid {
+        /// The 1-based line number.
+        line: usize,
+        /// The trimmed line, with any invalid UTF-8 replaced.
+        content: String,
+    },
+    /// Reading the line failed.
+    Io {
+        /// The 1-based number of the line being read.
+        line: usize,
+        error: io::Error,
+    },
+}
+
+impl LineError {
+    /// The 1-based line number.
+    pub fn line(&self) -> usize {
+        match self {
+            LineError::Invalid { line, .. } | LineError::Io { line, .. } => *line,
+        }
+    }
+}
+
+impl PartialEq for LineError {
+    /// I/O errors are compared by kind.
+    fn eq(&self, other: &LineError) -> bool {
+        match (self, other) {
+            (
+                LineError::Invalid { line, content },
+                LineError::Invalid {
+                    line: other_line,
+                    content: other_content,
+                },
+            ) => line == other_line && content == other_content,
+            (
+                LineError::Io { line, error },
+                LineError::Io {
+                    line: other_line,
+                    error: other_error,
+                },
+            ) => line == other_line && error.kind() == other_error.kind(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LineError::Invalid { line, content } => {
+                write!(f, "line {}: invalid boolean value {:?}", line, content)
+            }
+            LineError::Io { line, error } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+impl std::error::Error for LineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LineError::Invalid { .. } => None,
+            LineError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufReader, Read};
+
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "tokens-numeric", feature = "tokens-yes-no"))]
+    fn lines() {
+        let values: Vec<_> = parse_lines(&b"1\r\n0\nYES"[..]).collect();
+        assert_eq!(values, [Ok(true), Ok(false), Ok(true)]);
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn invalid_lines() {
+        let values: Vec<_> = parse_lines(&b"y\n\n \xffno \n"[..]).collect();
+        assert_eq!(
+            values,
+            [
+                Ok(true),
+                Err(LineError::Invalid {
+                    line: 2,
+                    content: String::new()
+                }),
+                Err(LineError::Invalid {
+                    line: 3,
+                    content: "\u{fffd}no".to_owned()
+                }),
+            ]
+        );
+        assert_eq!(values[2].as_ref().unwrap_err().line(), 3);
+    }
+
+    #[test]
+    fn io_error_ends() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let mut lines = parse_lines(BufReader::new(Failing));
+        let err = lines.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "line 1: disk on fire");
+        assert!(std::error::Error::source(&err).is_some());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parse_lines(&b""[..]).count(), 0);
+    }
+}
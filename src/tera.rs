@@ -0,0 +1,106 @@
+//! A Tera filter for lenient booleans.
+//!
+//! The `lenient_bool` filter behaves like the `minijinja` one: booleans, numbers, and strings
+//! are converted with the `LenientBool` rules, numbers are true when nonzero, and null is
+//! false. Values that can't be converted are an error unless a `default` is given.
+//!
+//! ```
+//! use tera::{Context, Tera};
+//!
+//! let mut tera = Tera::default();
+//! tera.register_filter("lenient_bool", lenient_bool::tera::lenient_bool);
+//!
+//! let mut context = Context::new();
+//! context.insert("debug", "Yes");
+//! let out = tera
+//!     .render_str("{% if debug | lenient_bool %}debug{% else %}release{% endif %}", &context)
+//!     .unwrap();
+//! assert_eq!(out, "debug");
+//!
+//! context.insert("debug", "maybe");
+//! let out = tera
+//!     .render_str("{{ debug | lenient_bool(default=false) }}", &context)
+//!     .unwrap();
+//! assert_eq!(out, "false");
+//! ```
+
+use std::collections::HashMap;
+
+use ::tera::{Error, Result, Value};
+
+use super::LenientBool;
+
+fn to_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Null => Some(false),
+        Value::Bool(b) => Some(*b),
+        Value::Number(n) => n.as_f64().map(|n| n != 0.0),
+        Value::String(s) => s.parse::<LenientBool>().ok().map(bool::from),
+        _ => None,
+    }
+}
+
+/// The `lenient_bool` filter. Register it with `Tera::register_filter`.
+pub fn lenient_bool(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
+    let default = match args.get("default") {
+        Some(Value::Bool(b)) => Some(*b),
+        Some(_) => {
+            return Err(Error::msg(
+                "filter `lenient_bool` expects a boolean `default`",
+            ))
+        }
+        None => None,
+    };
+    to_bool(value)
+        .or(default)
+        .map(Value::Bool)
+        .ok_or_else(|| Error::msg(format!("invalid boolean value {}", value)))
+}
+
+#[cfg(test)]
+mod test {
+    use ::tera::{Context, Tera};
+
+    use super::*;
+
+    fn render(source: &str, value: Value) -> Result<String> {
+        let mut tera = Tera::default();
+        tera.register_filter("lenient_bool", lenient_bool);
+        let mut context = Context::new();
+        context.insert("value", &value);
+        tera.render_str(source, &context)
+    }
+
+    #[test]
+    fn filter_string() {
+        let out = render("{{ value | lenient_bool }}", Value::from("N")).unwrap();
+        assert_eq!(out, "false");
+    }
+
+    #[test]
+    fn filter_number() {
+        let out = render("{{ value | lenient_bool }}", Value::from(3)).unwrap();
+        assert_eq!(out, "true");
+    }
+
+    #[test]
+    fn filter_null() {
+        let out = render("{{ value | lenient_bool }}", Value::Null).unwrap();
+        assert_eq!(out, "false");
+    }
+
+    #[test]
+    fn filter_bad_input_err() {
+        assert!(render("{{ value | lenient_bool }}", Value::from("abc")).is_err());
+    }
+
+    #[test]
+    fn filter_default() {
+        let out = render(
+            "{{ value | lenient_bool(default=true) }}",
+            Value::from("abc"),
+        )
+        .unwrap();
+        assert_eq!(out, "true");
+    }
+}
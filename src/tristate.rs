@@ -0,0 +1,92 @@
+use core::fmt;
+use core::str::FromStr;
+
+use super::{LenientBool, LenientBoolError};
+
+/// A Linux Kconfig tristate option: built in, built as a module, or not built.
+///
+/// `y`, `n`, and `m` are accepted, along with `module` and all of the `LenientBool`
+/// spellings, case-insensitively. `Display` writes the Kconfig forms `y`, `n`, and `m`.
+///
+/// ```
+/// use lenient_bool::TriState;
+///
+/// assert_eq!("m".parse(), Ok(TriState::Module));
+/// assert_eq!("yes".parse(), Ok(TriState::Yes));
+/// assert_eq!(TriState::No.to_string(), "n");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TriState {
+    Yes,
+    No,
+    Module,
+}
+
+impl TriState {
+    /// Whether the option is built at all, either in or as a module.
+    pub fn is_enabled(self) -> bool {
+        self != TriState::No
+    }
+}
+
+impl FromStr for TriState {
+    type Err = LenientBoolError;
+    fn from_str(s: &str) -> Result<TriState, LenientBoolError> {
+        if s.eq_ignore_ascii_case("m") || s.eq_ignore_ascii_case("module") {
+            return Ok(TriState::Module);
+        }
+        s.parse::<LenientBool>().map(|b| TriState::from(b.0))
+    }
+}
+
+impl From<bool> for TriState {
+    fn from(b: bool) -> TriState {
+        if b {
+            TriState::Yes
+        } else {
+            TriState::No
+        }
+    }
+}
+
+impl fmt::Display for TriState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TriState::Yes => "y",
+            TriState::No => "n",
+            TriState::Module => "m",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn kconfig_values() {
+        assert_eq!("y".parse(), Ok(TriState::Yes));
+        assert_eq!("N".parse(), Ok(TriState::No));
+        assert_eq!("Module".parse(), Ok(TriState::Module));
+    }
+
+    #[test]
+    #[cfg(feature = "tokens-single-letter")]
+    fn display_round_trip() {
+        for value in [TriState::Yes, TriState::No, TriState::Module] {
+            assert_eq!(value.to_string().parse(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn is_enabled() {
+        assert!(TriState::Module.is_enabled());
+        assert!(!TriState::No.is_enabled());
+    }
+
+    #[test]
+    fn bad_input_err() {
+        assert!("maybe".parse::<TriState>().is_err());
+    }
+}
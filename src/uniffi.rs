@@ -0,0 +1,63 @@
+//! Kotlin and Swift bindings built with UniFFI.
+//!
+//! The interface is defined with UniFFI's proc macros, so no UDL file is needed. Build the
+//! library as a `cdylib` and generate bindings from it:
+//!
+//! ```text
+//! cargo rustc --release --features uniffi --crate-type cdylib
+//! uniffi-bindgen generate --library target/release/liblenient_bool.so --language kotlin --out-dir out
+//! ```
+//!
+//! The generated interface is:
+//!
+//! ```text
+//! fun parseLenientBool(input: String): Boolean?
+//! fun lenientBoolTrueTokens(): List<String>
+//! fun lenientBoolFalseTokens(): List<String>
+//! ```
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+/// Parses `input` with the `LenientBool` rules, returning `None` if it isn't recognized.
+#[::uniffi::export]
+pub fn parse_lenient_bool(input: &str) -> Option<bool> {
+    input.parse::<LenientBool>().ok().map(bool::from)
+}
+
+/// The spellings that parse as `true`.
+#[::uniffi::export]
+pub fn lenient_bool_true_tokens() -> Vec<String> {
+    TRUE_TOKENS.iter().map(|t| t.to_string()).collect()
+}
+
+/// The spellings that parse as `false`.
+#[::uniffi::export]
+pub fn lenient_bool_false_tokens() -> Vec<String> {
+    FALSE_TOKENS.iter().map(|t| t.to_string()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(parse_lenient_bool("yes"), Some(true));
+    }
+
+    #[test]
+    fn parse_0() {
+        assert_eq!(parse_lenient_bool("0"), Some(false));
+    }
+
+    #[test]
+    fn parse_bad_input() {
+        assert_eq!(parse_lenient_bool("abc"), None);
+    }
+
+    #[test]
+    fn tokens() {
+        assert!(lenient_bool_true_tokens().contains(&"yes".to_owned()));
+        assert!(lenient_bool_false_tokens().contains(&"no".to_owned()));
+    }
+}
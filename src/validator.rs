@@ -0,0 +1,72 @@
+//! Validation with the `validator` crate.
+//!
+//! `validate` checks that a string would be accepted by `LenientBool`, leaving the field
+//! itself unchanged. Use it as a custom validator:
+//!
+//! ```
+//! use validator::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SettingsForm {
+//!     #[validate(custom(function = "lenient_bool::validator::validate"))]
+//!     notifications: String,
+//!     #[validate(custom(function = "lenient_bool::validator::validate"))]
+//!     newsletter: Option<String>,
+//! }
+//!
+//! let form = SettingsForm {
+//!     notifications: "Yes".to_owned(),
+//!     newsletter: None,
+//! };
+//! assert!(form.validate().is_ok());
+//!
+//! let form = SettingsForm {
+//!     notifications: "sometimes".to_owned(),
+//!     newsletter: Some("0".to_owned()),
+//! };
+//! let errors = form.validate().unwrap_err();
+//! assert!(errors.field_errors().contains_key("notifications"));
+//! ```
+
+use ::validator::ValidationError;
+
+use super::LenientBool;
+
+/// Checks that `value` can be parsed as a `LenientBool`.
+///
+/// Failures have the code `lenient_bool`, and include the rejected value as the `value`
+/// parameter.
+pub fn validate<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), ValidationError> {
+    let value = value.as_ref();
+    match value.parse::<LenientBool>() {
+        Ok(_) => Ok(()),
+        Err(_) => {
+            let mut err = ValidationError::new("lenient_bool")
+                .with_message("must be a boolean value such as true, false, yes, or no".into());
+            err.add_param("value".into(), &value);
+            Err(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate_yes() {
+        assert_eq!(validate("yes"), Ok(()));
+    }
+
+    #[test]
+    fn validate_string() {
+        assert_eq!(validate(&"0".to_owned()), Ok(()));
+    }
+
+    #[test]
+    fn validate_bad_input_err() {
+        let err = validate("abc").unwrap_err();
+        assert_eq!(err.code, "lenient_bool");
+        assert_eq!(err.params["value"], "abc");
+    }
+}
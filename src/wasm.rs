@@ -0,0 +1,123 @@
+//! JavaScript bindings built with `wasm-bindgen`.
+//!
+//! When compiled for `wasm32-unknown-unknown` with the `wasm` feature, these functions are
+//! exported to JavaScript, so client-side validation can use exactly the same rules as the
+//! Rust code that processes the values:
+//!
+//! ```text
+//! parseLenientBool(input: string): boolean | undefined
+//! parseLenientBoolDetailed(input: string): LenientBoolResult
+//! lenientBoolTrueTokens(): string[]
+//! lenientBoolFalseTokens(): string[]
+//! ```
+//!
+//! A `LenientBoolResult` has `ok`, `value`, and `error` properties, where `error` is the same
+//! message the Rust error displays, so front ends can show it next to the input.
+
+use ::wasm_bindgen::prelude::wasm_bindgen;
+
+use super::{LenientBool, FALSE_TOKENS, TRUE_TOKENS};
+
+/// Parses `input` with the `LenientBool` rules, returning `None` if it isn't recognized.
+#[wasm_bindgen(js_name = parseLenientBool)]
+pub fn parse_lenient_bool(input: &str) -> Option<bool> {
+    input.parse::<LenientBool>().ok().map(bool::from)
+}
+
+/// The outcome of `parseLenientBoolDetailed`.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenientBoolResult {
+    value: Option<bool>,
+    error: Option<String>,
+}
+
+#[wasm_bindgen]
+impl LenientBoolResult {
+    /// Whether the input was recognized.
+    #[wasm_bindgen(getter)]
+    pub fn ok(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// The parsed value, or `undefined` if the input wasn't recognized.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> Option<bool> {
+        self.value
+    }
+
+    /// The error message, or `undefined` if the input was recognized.
+    #[wasm_bindgen(getter)]
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+}
+
+/// Parses `input` with the `LenientBool` rules, reporting why it failed if it isn't
+/// recognized.
+#[wasm_bindgen(js_name = parseLenientBoolDetailed)]
+pub fn parse_lenient_bool_detailed(input: &str) -> LenientBoolResult {
+    match input.parse::<LenientBool>() {
+        Ok(b) => LenientBoolResult {
+            value: Some(b.0),
+            error: None,
+        },
+        Err(err) => LenientBoolResult {
+            value: None,
+            error: Some(err.to_string()),
+        },
+    }
+}
+
+/// The spellings that parse as `true`.
+#[wasm_bindgen(js_name = lenientBoolTrueTokens)]
+pub fn true_tokens() -> Vec<String> {
+    TRUE_TOKENS.iter().map(|t| t.to_string()).collect()
+}
+
+/// The spellings that parse as `false`.
+#[wasm_bindgen(js_name = lenientBoolFalseTokens)]
+pub fn false_tokens() -> Vec<String> {
+    FALSE_TOKENS.iter().map(|t| t.to_string()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(parse_lenient_bool("yes"), Some(true));
+    }
+
+    #[test]
+    fn parse_0() {
+        assert_eq!(parse_lenient_bool("0"), Some(false));
+    }
+
+    #[test]
+    fn parse_bad_input() {
+        assert_eq!(parse_lenient_bool("abc"), None);
+    }
+
+    #[test]
+    fn detailed() {
+        let result = parse_lenient_bool_detailed("Y");
+        assert!(result.ok());
+        assert_eq!((result.value(), result.error()), (Some(true), None));
+
+        let result = parse_lenient_bool_detailed("abc");
+        assert!(!result.ok());
+        assert_eq!(result.value(), None);
+        assert_eq!(
+            result.error(),
+            Some("abc".parse::<LenientBool>().unwrap_err().to_string())
+        );
+    }
+
+    #[test]
+    fn tokens() {
+        assert!(true_tokens().contains(&"yes".to_owned()));
+        assert!(false_tokens().contains(&"no".to_owned()));
+    }
+}
@@ -0,0 +1,63 @@
+//! A `winnow` parser for lenient booleans.
+//!
+//! ```
+//! use lenient_bool::winnow::lenient_bool;
+//! use winnow::combinator::preceded;
+//! use winnow::Parser;
+//!
+//! let mut input = "debug=Yes; level=2";
+//! let value = preceded("debug=", lenient_bool).parse_next(&mut input).unwrap();
+//! assert!(value);
+//! assert_eq!(input, "; level=2");
+//! ```
+
+use ::winnow::error::{ContextError, ErrMode, ModalResult, ParserError};
+use ::winnow::stream::Stream;
+
+use super::parse_prefix;
+
+/// Consumes a boolean token from the start of `input`, leaving the rest of the input in place.
+///
+/// This follows the same rules as `lenient_bool::nom::lenient_bool`: the longest matching
+/// token is used, and it must not be followed by another word character. On failure the
+/// input is left untouched and a backtracking error is returned.
+pub fn lenient_bool(input: &mut &str) -> ModalResult<bool> {
+    match parse_prefix(input) {
+        Some((value, len)) => {
+            input.next_slice(len);
+            Ok(value)
+        }
+        None => Err(ErrMode::Backtrack(ContextError::from_input(input))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::winnow::Parser;
+
+    use super::*;
+
+    #[test]
+    fn parse_yes() {
+        assert_eq!(lenient_bool.parse("yes"), Ok(true));
+    }
+
+    #[test]
+    fn parse_false_with_remainder() {
+        let mut input = "FALSE, 1";
+        assert_eq!(lenient_bool(&mut input), Ok(false));
+        assert_eq!(input, ", 1");
+    }
+
+    #[test]
+    fn parse_word_prefix_err() {
+        let mut input = "yesterday";
+        assert!(lenient_bool(&mut input).is_err());
+        assert_eq!(input, "yesterday");
+    }
+
+    #[test]
+    fn parse_bad_input_err() {
+        assert!(lenient_bool.parse("abc").is_err());
+    }
+}